@@ -1,9 +1,10 @@
-use alloc::string::ToString;
 use core::fmt::Display;
 
 use int_enum::IntEnum;
 use pod::Pod;
 
+use crate::signal::{SigAction, SigActionFlags};
+
 #[repr(isize)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, IntEnum)]
 pub enum LinuxErrno {
@@ -41,15 +42,70 @@ pub enum LinuxErrno {
     EPIPE = -32,
     EDOM = -33,
     ERANGE = -34,
+    EDEADLK = -35,
+    ENAMETOOLONG = -36,
+    ENOLCK = -37,
     ENOSYS = -38,
+    ENOTEMPTY = -39,
     ELOOP = -40,
-    EADDRINUSE = -98,
+    ENOMSG = -42,
+    EIDRM = -43,
+    ECHRNG = -44,
+    EL2NSYNC = -45,
+    EL3HLT = -46,
+    EL3RST = -47,
+    ELNRNG = -48,
+    EUNATCH = -49,
+    ENOCSI = -50,
+    EL2HLT = -51,
+    EBADE = -52,
+    EBADR = -53,
+    EXFULL = -54,
+    ENOANO = -55,
+    EBADRQC = -56,
+    EBADSLT = -57,
+    EBFONT = -59,
+    ENOSTR = -60,
+    ENODATA = -61,
+    ETIME = -62,
+    ENOSR = -63,
+    ENONET = -64,
+    ENOPKG = -65,
+    EREMOTE = -66,
+    ENOLINK = -67,
+    EADV = -68,
+    ESRMNT = -69,
+    ECOMM = -70,
+    EPROTO = -71,
+    EMULTIHOP = -72,
+    EDOTDOT = -73,
+    EBADMSG = -74,
+    EOVERFLOW = -75,
+    ENOTUNIQ = -76,
+    EBADFD = -77,
+    EREMCHG = -78,
+    ELIBACC = -79,
+    ELIBBAD = -80,
+    ELIBSCN = -81,
+    ELIBMAX = -82,
+    ELIBEXEC = -83,
+    EILSEQ = -84,
+    ERESTART = -85,
+    ESTRPIPE = -86,
+    EUSERS = -87,
+    ENOTSOCK = -88,
+    EDESTADDRREQ = -89,
+    EMSGSIZE = -90,
+    EPROTOTYPE = -91,
+    ENOPROTOOPT = -92,
     /// 协议不被支持 Protocol not supported.
-    EPROTONOSUPPORT = -92,
-    EOPNOTSUPP = -94,
+    EPROTONOSUPPORT = -93,
+    ESOCKTNOSUPPORT = -94,
+    EOPNOTSUPP = -95,
     EPFNOSUPPORT = -96,
     /// 不支持的地址
     EAFNOSUPPORT = -97,
+    EADDRINUSE = -98,
     EADDRNOTAVAIL = -99,
     ENETDOWN = -100,
     ENETUNREACH = -101,
@@ -59,323 +115,498 @@ pub enum LinuxErrno {
     ENOBUFS = -105,
     EISCONN = -106,
     ENOTCONN = -107,
-    /// 操作正在处理 Operation in progress.
-    EINPROGRESS = -115,
+    ESHUTDOWN = -108,
+    ETOOMANYREFS = -109,
+    ETIMEDOUT = -110,
     /// 拒绝连接
     ECONNREFUSED = -111,
-    /// Address already in use
+    EHOSTDOWN = -112,
+    EHOSTUNREACH = -113,
+    /// 操作已在进行中 Operation already in progress.
     EALREADY = -114,
-    #[cfg(feature = "special_error")]
-    DOMAINCRASH = -255,
-    #[cfg(feature = "special_error")]
-    EBLOCKING = -256,
+    /// 操作正在处理 Operation in progress.
+    EINPROGRESS = -115,
+    ESTALE = -116,
+    EUCLEAN = -117,
+    ENOTNAM = -118,
+    ENAVAIL = -119,
+    EISNAM = -120,
+    EREMOTEIO = -121,
+    EDQUOT = -122,
+    ENOMEDIUM = -123,
+    EMEDIUMTYPE = -124,
+    ECANCELED = -125,
+    ENOKEY = -126,
+    EKEYEXPIRED = -127,
+    EKEYREVOKED = -128,
+    EKEYREJECTED = -129,
+    EOWNERDEAD = -130,
+    ENOTRECOVERABLE = -131,
+    ERFKILL = -132,
+    EHWPOISON = -133,
+}
+
+impl LinuxErrno {
+    /// `EWOULDBLOCK` 与 [`LinuxErrno::EAGAIN`] 同值
+    pub const EWOULDBLOCK: LinuxErrno = LinuxErrno::EAGAIN;
+    /// `EDEADLOCK` 与 [`LinuxErrno::EDEADLK`] 同值
+    pub const EDEADLOCK: LinuxErrno = LinuxErrno::EDEADLK;
+    /// `ENOTSUP` 与 [`LinuxErrno::EOPNOTSUPP`] 同值
+    pub const ENOTSUP: LinuxErrno = LinuxErrno::EOPNOTSUPP;
+
+    /// 错误码对应的描述信息
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            LinuxErrno::EPERM => "Operation not permitted",
+            LinuxErrno::ENOENT => "No such file or directory",
+            LinuxErrno::ESRCH => "No such process",
+            LinuxErrno::EINTR => "Interrupted system call",
+            LinuxErrno::EIO => "Input/output error",
+            LinuxErrno::ENXIO => "No such device or address",
+            LinuxErrno::E2BIG => "Argument list too long",
+            LinuxErrno::ENOEXEC => "Exec format error",
+            LinuxErrno::EBADF => "Bad file descriptor",
+            LinuxErrno::ECHILD => "No child processes",
+            LinuxErrno::EAGAIN => "Resource temporarily unavailable",
+            LinuxErrno::ENOMEM => "Cannot allocate memory",
+            LinuxErrno::EACCES => "Permission denied",
+            LinuxErrno::EFAULT => "Bad address",
+            LinuxErrno::ENOTBLK => "Block device required",
+            LinuxErrno::EBUSY => "Device or resource busy",
+            LinuxErrno::EEXIST => "File exists",
+            LinuxErrno::EXDEV => "Invalid cross-device link",
+            LinuxErrno::ENODEV => "No such device",
+            LinuxErrno::ENOTDIR => "Not a directory",
+            LinuxErrno::EISDIR => "Is a directory",
+            LinuxErrno::EINVAL => "Invalid argument",
+            LinuxErrno::ENFILE => "Too many open files in system",
+            LinuxErrno::EMFILE => "Too many open files",
+            LinuxErrno::ENOTTY => "Inappropriate ioctl for device",
+            LinuxErrno::ETXTBSY => "Text file busy",
+            LinuxErrno::EFBIG => "File too large",
+            LinuxErrno::ENOSPC => "No space left on device",
+            LinuxErrno::ESPIPE => "Illegal seek",
+            LinuxErrno::EROFS => "Read-only file system",
+            LinuxErrno::EMLINK => "Too many links",
+            LinuxErrno::EPIPE => "Broken pipe",
+            LinuxErrno::EDOM => "Numerical argument out of domain",
+            LinuxErrno::ERANGE => "Numerical result out of range",
+            LinuxErrno::EDEADLK => "Resource deadlock avoided",
+            LinuxErrno::ENAMETOOLONG => "File name too long",
+            LinuxErrno::ENOLCK => "No locks available",
+            LinuxErrno::ENOSYS => "Function not implemented",
+            LinuxErrno::ENOTEMPTY => "Directory not empty",
+            LinuxErrno::ELOOP => "Too many levels of symbolic links",
+            LinuxErrno::ENOMSG => "No message of desired type",
+            LinuxErrno::EIDRM => "Identifier removed",
+            LinuxErrno::ECHRNG => "Channel number out of range",
+            LinuxErrno::EL2NSYNC => "Level 2 not synchronized",
+            LinuxErrno::EL3HLT => "Level 3 halted",
+            LinuxErrno::EL3RST => "Level 3 reset",
+            LinuxErrno::ELNRNG => "Link number out of range",
+            LinuxErrno::EUNATCH => "Protocol driver not attached",
+            LinuxErrno::ENOCSI => "No CSI structure available",
+            LinuxErrno::EL2HLT => "Level 2 halted",
+            LinuxErrno::EBADE => "Invalid exchange",
+            LinuxErrno::EBADR => "Invalid request descriptor",
+            LinuxErrno::EXFULL => "Exchange full",
+            LinuxErrno::ENOANO => "No anode",
+            LinuxErrno::EBADRQC => "Invalid request code",
+            LinuxErrno::EBADSLT => "Invalid slot",
+            LinuxErrno::EBFONT => "Bad font file format",
+            LinuxErrno::ENOSTR => "Device not a stream",
+            LinuxErrno::ENODATA => "No data available",
+            LinuxErrno::ETIME => "Timer expired",
+            LinuxErrno::ENOSR => "Out of streams resources",
+            LinuxErrno::ENONET => "Machine is not on the network",
+            LinuxErrno::ENOPKG => "Package not installed",
+            LinuxErrno::EREMOTE => "Object is remote",
+            LinuxErrno::ENOLINK => "Link has been severed",
+            LinuxErrno::EADV => "Advertise error",
+            LinuxErrno::ESRMNT => "Srmount error",
+            LinuxErrno::ECOMM => "Communication error on send",
+            LinuxErrno::EPROTO => "Protocol error",
+            LinuxErrno::EMULTIHOP => "Multihop attempted",
+            LinuxErrno::EDOTDOT => "RFS specific error",
+            LinuxErrno::EBADMSG => "Bad message",
+            LinuxErrno::EOVERFLOW => "Value too large for defined data type",
+            LinuxErrno::ENOTUNIQ => "Name not unique on network",
+            LinuxErrno::EBADFD => "File descriptor in bad state",
+            LinuxErrno::EREMCHG => "Remote address changed",
+            LinuxErrno::ELIBACC => "Can not access a needed shared library",
+            LinuxErrno::ELIBBAD => "Accessing a corrupted shared library",
+            LinuxErrno::ELIBSCN => ".lib section in a.out corrupted",
+            LinuxErrno::ELIBMAX => "Attempting to link in too many shared libraries",
+            LinuxErrno::ELIBEXEC => "Cannot exec a shared library directly",
+            LinuxErrno::EILSEQ => "Invalid or incomplete multibyte or wide character",
+            LinuxErrno::ERESTART => "Interrupted system call should be restarted",
+            LinuxErrno::ESTRPIPE => "Streams pipe error",
+            LinuxErrno::EUSERS => "Too many users",
+            LinuxErrno::ENOTSOCK => "Socket operation on non-socket",
+            LinuxErrno::EDESTADDRREQ => "Destination address required",
+            LinuxErrno::EMSGSIZE => "Message too long",
+            LinuxErrno::EPROTOTYPE => "Protocol wrong type for socket",
+            LinuxErrno::ENOPROTOOPT => "Protocol not available",
+            LinuxErrno::EPROTONOSUPPORT => "Protocol not supported",
+            LinuxErrno::ESOCKTNOSUPPORT => "Socket type not supported",
+            LinuxErrno::EOPNOTSUPP => "Operation not supported",
+            LinuxErrno::EPFNOSUPPORT => "Protocol family not supported",
+            LinuxErrno::EAFNOSUPPORT => "Address family not supported by protocol",
+            LinuxErrno::EADDRINUSE => "Address already in use",
+            LinuxErrno::EADDRNOTAVAIL => "Cannot assign requested address",
+            LinuxErrno::ENETDOWN => "Network is down",
+            LinuxErrno::ENETUNREACH => "Network is unreachable",
+            LinuxErrno::ENETRESET => "Network dropped connection on reset",
+            LinuxErrno::ECONNABORTED => "Software caused connection abort",
+            LinuxErrno::ECONNRESET => "Connection reset by peer",
+            LinuxErrno::ENOBUFS => "No buffer space available",
+            LinuxErrno::EISCONN => "Transport endpoint is already connected",
+            LinuxErrno::ENOTCONN => "Transport endpoint is not connected",
+            LinuxErrno::ESHUTDOWN => "Cannot send after transport endpoint shutdown",
+            LinuxErrno::ETOOMANYREFS => "Too many references: cannot splice",
+            LinuxErrno::ETIMEDOUT => "Connection timed out",
+            LinuxErrno::ECONNREFUSED => "Connection refused",
+            LinuxErrno::EHOSTDOWN => "Host is down",
+            LinuxErrno::EHOSTUNREACH => "No route to host",
+            LinuxErrno::EALREADY => "Operation already in progress",
+            LinuxErrno::EINPROGRESS => "Operation now in progress",
+            LinuxErrno::ESTALE => "Stale file handle",
+            LinuxErrno::EUCLEAN => "Structure needs cleaning",
+            LinuxErrno::ENOTNAM => "Not a XENIX named type file",
+            LinuxErrno::ENAVAIL => "No XENIX semaphores available",
+            LinuxErrno::EISNAM => "Is a named type file",
+            LinuxErrno::EREMOTEIO => "Remote I/O error",
+            LinuxErrno::EDQUOT => "Disk quota exceeded",
+            LinuxErrno::ENOMEDIUM => "No medium found",
+            LinuxErrno::EMEDIUMTYPE => "Wrong medium type",
+            LinuxErrno::ECANCELED => "Operation canceled",
+            LinuxErrno::ENOKEY => "Required key not available",
+            LinuxErrno::EKEYEXPIRED => "Key has expired",
+            LinuxErrno::EKEYREVOKED => "Key has been revoked",
+            LinuxErrno::EKEYREJECTED => "Key was rejected by service",
+            LinuxErrno::EOWNERDEAD => "Owner died",
+            LinuxErrno::ENOTRECOVERABLE => "State not recoverable",
+            LinuxErrno::ERFKILL => "Operation not possible due to RF-kill",
+            LinuxErrno::EHWPOISON => "Memory page has hardware error",
+        }
+    }
+
+    /// 错误码的符号名，如 `"ENOENT"`
+    pub const fn name(self) -> &'static str {
+        match self {
+            LinuxErrno::EPERM => "EPERM",
+            LinuxErrno::ENOENT => "ENOENT",
+            LinuxErrno::ESRCH => "ESRCH",
+            LinuxErrno::EINTR => "EINTR",
+            LinuxErrno::EIO => "EIO",
+            LinuxErrno::ENXIO => "ENXIO",
+            LinuxErrno::E2BIG => "E2BIG",
+            LinuxErrno::ENOEXEC => "ENOEXEC",
+            LinuxErrno::EBADF => "EBADF",
+            LinuxErrno::ECHILD => "ECHILD",
+            LinuxErrno::EAGAIN => "EAGAIN",
+            LinuxErrno::ENOMEM => "ENOMEM",
+            LinuxErrno::EACCES => "EACCES",
+            LinuxErrno::EFAULT => "EFAULT",
+            LinuxErrno::ENOTBLK => "ENOTBLK",
+            LinuxErrno::EBUSY => "EBUSY",
+            LinuxErrno::EEXIST => "EEXIST",
+            LinuxErrno::EXDEV => "EXDEV",
+            LinuxErrno::ENODEV => "ENODEV",
+            LinuxErrno::ENOTDIR => "ENOTDIR",
+            LinuxErrno::EISDIR => "EISDIR",
+            LinuxErrno::EINVAL => "EINVAL",
+            LinuxErrno::ENFILE => "ENFILE",
+            LinuxErrno::EMFILE => "EMFILE",
+            LinuxErrno::ENOTTY => "ENOTTY",
+            LinuxErrno::ETXTBSY => "ETXTBSY",
+            LinuxErrno::EFBIG => "EFBIG",
+            LinuxErrno::ENOSPC => "ENOSPC",
+            LinuxErrno::ESPIPE => "ESPIPE",
+            LinuxErrno::EROFS => "EROFS",
+            LinuxErrno::EMLINK => "EMLINK",
+            LinuxErrno::EPIPE => "EPIPE",
+            LinuxErrno::EDOM => "EDOM",
+            LinuxErrno::ERANGE => "ERANGE",
+            LinuxErrno::EDEADLK => "EDEADLK",
+            LinuxErrno::ENAMETOOLONG => "ENAMETOOLONG",
+            LinuxErrno::ENOLCK => "ENOLCK",
+            LinuxErrno::ENOSYS => "ENOSYS",
+            LinuxErrno::ENOTEMPTY => "ENOTEMPTY",
+            LinuxErrno::ELOOP => "ELOOP",
+            LinuxErrno::ENOMSG => "ENOMSG",
+            LinuxErrno::EIDRM => "EIDRM",
+            LinuxErrno::ECHRNG => "ECHRNG",
+            LinuxErrno::EL2NSYNC => "EL2NSYNC",
+            LinuxErrno::EL3HLT => "EL3HLT",
+            LinuxErrno::EL3RST => "EL3RST",
+            LinuxErrno::ELNRNG => "ELNRNG",
+            LinuxErrno::EUNATCH => "EUNATCH",
+            LinuxErrno::ENOCSI => "ENOCSI",
+            LinuxErrno::EL2HLT => "EL2HLT",
+            LinuxErrno::EBADE => "EBADE",
+            LinuxErrno::EBADR => "EBADR",
+            LinuxErrno::EXFULL => "EXFULL",
+            LinuxErrno::ENOANO => "ENOANO",
+            LinuxErrno::EBADRQC => "EBADRQC",
+            LinuxErrno::EBADSLT => "EBADSLT",
+            LinuxErrno::EBFONT => "EBFONT",
+            LinuxErrno::ENOSTR => "ENOSTR",
+            LinuxErrno::ENODATA => "ENODATA",
+            LinuxErrno::ETIME => "ETIME",
+            LinuxErrno::ENOSR => "ENOSR",
+            LinuxErrno::ENONET => "ENONET",
+            LinuxErrno::ENOPKG => "ENOPKG",
+            LinuxErrno::EREMOTE => "EREMOTE",
+            LinuxErrno::ENOLINK => "ENOLINK",
+            LinuxErrno::EADV => "EADV",
+            LinuxErrno::ESRMNT => "ESRMNT",
+            LinuxErrno::ECOMM => "ECOMM",
+            LinuxErrno::EPROTO => "EPROTO",
+            LinuxErrno::EMULTIHOP => "EMULTIHOP",
+            LinuxErrno::EDOTDOT => "EDOTDOT",
+            LinuxErrno::EBADMSG => "EBADMSG",
+            LinuxErrno::EOVERFLOW => "EOVERFLOW",
+            LinuxErrno::ENOTUNIQ => "ENOTUNIQ",
+            LinuxErrno::EBADFD => "EBADFD",
+            LinuxErrno::EREMCHG => "EREMCHG",
+            LinuxErrno::ELIBACC => "ELIBACC",
+            LinuxErrno::ELIBBAD => "ELIBBAD",
+            LinuxErrno::ELIBSCN => "ELIBSCN",
+            LinuxErrno::ELIBMAX => "ELIBMAX",
+            LinuxErrno::ELIBEXEC => "ELIBEXEC",
+            LinuxErrno::EILSEQ => "EILSEQ",
+            LinuxErrno::ERESTART => "ERESTART",
+            LinuxErrno::ESTRPIPE => "ESTRPIPE",
+            LinuxErrno::EUSERS => "EUSERS",
+            LinuxErrno::ENOTSOCK => "ENOTSOCK",
+            LinuxErrno::EDESTADDRREQ => "EDESTADDRREQ",
+            LinuxErrno::EMSGSIZE => "EMSGSIZE",
+            LinuxErrno::EPROTOTYPE => "EPROTOTYPE",
+            LinuxErrno::ENOPROTOOPT => "ENOPROTOOPT",
+            LinuxErrno::EPROTONOSUPPORT => "EPROTONOSUPPORT",
+            LinuxErrno::ESOCKTNOSUPPORT => "ESOCKTNOSUPPORT",
+            LinuxErrno::EOPNOTSUPP => "EOPNOTSUPP",
+            LinuxErrno::EPFNOSUPPORT => "EPFNOSUPPORT",
+            LinuxErrno::EAFNOSUPPORT => "EAFNOSUPPORT",
+            LinuxErrno::EADDRINUSE => "EADDRINUSE",
+            LinuxErrno::EADDRNOTAVAIL => "EADDRNOTAVAIL",
+            LinuxErrno::ENETDOWN => "ENETDOWN",
+            LinuxErrno::ENETUNREACH => "ENETUNREACH",
+            LinuxErrno::ENETRESET => "ENETRESET",
+            LinuxErrno::ECONNABORTED => "ECONNABORTED",
+            LinuxErrno::ECONNRESET => "ECONNRESET",
+            LinuxErrno::ENOBUFS => "ENOBUFS",
+            LinuxErrno::EISCONN => "EISCONN",
+            LinuxErrno::ENOTCONN => "ENOTCONN",
+            LinuxErrno::ESHUTDOWN => "ESHUTDOWN",
+            LinuxErrno::ETOOMANYREFS => "ETOOMANYREFS",
+            LinuxErrno::ETIMEDOUT => "ETIMEDOUT",
+            LinuxErrno::ECONNREFUSED => "ECONNREFUSED",
+            LinuxErrno::EHOSTDOWN => "EHOSTDOWN",
+            LinuxErrno::EHOSTUNREACH => "EHOSTUNREACH",
+            LinuxErrno::EALREADY => "EALREADY",
+            LinuxErrno::EINPROGRESS => "EINPROGRESS",
+            LinuxErrno::ESTALE => "ESTALE",
+            LinuxErrno::EUCLEAN => "EUCLEAN",
+            LinuxErrno::ENOTNAM => "ENOTNAM",
+            LinuxErrno::ENAVAIL => "ENAVAIL",
+            LinuxErrno::EISNAM => "EISNAM",
+            LinuxErrno::EREMOTEIO => "EREMOTEIO",
+            LinuxErrno::EDQUOT => "EDQUOT",
+            LinuxErrno::ENOMEDIUM => "ENOMEDIUM",
+            LinuxErrno::EMEDIUMTYPE => "EMEDIUMTYPE",
+            LinuxErrno::ECANCELED => "ECANCELED",
+            LinuxErrno::ENOKEY => "ENOKEY",
+            LinuxErrno::EKEYEXPIRED => "EKEYEXPIRED",
+            LinuxErrno::EKEYREVOKED => "EKEYREVOKED",
+            LinuxErrno::EKEYREJECTED => "EKEYREJECTED",
+            LinuxErrno::EOWNERDEAD => "EOWNERDEAD",
+            LinuxErrno::ENOTRECOVERABLE => "ENOTRECOVERABLE",
+            LinuxErrno::ERFKILL => "ERFKILL",
+            LinuxErrno::EHWPOISON => "EHWPOISON",
+        }
+    }
+
+    /// 判断系统调用的原始返回值是否表示错误。
+    ///
+    /// 与 Linux 的 `IS_ERR_VALUE` 一致，只有 `-4095..=-1` 被视为错误码，
+    /// 其余负值（如 `mmap` 返回的高地址）都是正常的返回值。
+    pub const fn is_error_value(ret: isize) -> bool {
+        ret < 0 && ret >= -(MAX_ERRNO as isize)
+    }
+
+    /// 将系统调用的原始返回值解码为 `Result`。
+    ///
+    /// 落在错误区间内但本枚举未定义的错误码会被解码为 [`LinuxErrno::EINVAL`]。
+    pub fn from_ret(ret: isize) -> Result<usize, LinuxErrno> {
+        if Self::is_error_value(ret) {
+            Err(LinuxErrno::try_from(ret).unwrap_or(LinuxErrno::EINVAL))
+        } else {
+            Ok(ret as usize)
+        }
+    }
+
+    /// 错误码作为系统调用返回值时的原始值
+    pub const fn to_ret(self) -> isize {
+        self as isize
+    }
+}
+
+/// 错误码的最大值，对应 Linux 的 `MAX_ERRNO`
+pub const MAX_ERRNO: usize = 4095;
+
+/// 将 `Result` 编码为系统调用的原始返回值，是 [`LinuxErrno::from_ret`] 的逆操作。
+pub trait IntoRet {
+    fn into_ret(self) -> isize;
+}
+
+impl IntoRet for Result<usize, LinuxErrno> {
+    fn into_ret(self) -> isize {
+        match self {
+            Ok(value) => value as isize,
+            Err(errno) => errno.to_ret(),
+        }
+    }
 }
 
 impl Display for LinuxErrno {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let str = match self {
-            LinuxErrno::EPERM => "Operation not permitted".to_string(),
-            LinuxErrno::ENOENT => "No such file or directory".to_string(),
-            LinuxErrno::ESRCH => "No such process".to_string(),
-            LinuxErrno::EINTR => "Interrupted system call".to_string(),
-            LinuxErrno::EIO => "I/O error".to_string(),
-            LinuxErrno::ENXIO => "No such device or address".to_string(),
-            LinuxErrno::E2BIG => "Argument list too long".to_string(),
-            LinuxErrno::ENOEXEC => "Exec format error".to_string(),
-            LinuxErrno::EBADF => "Bad file number".to_string(),
-            LinuxErrno::ECHILD => "No child processes".to_string(),
-            LinuxErrno::EAGAIN => "Try again".to_string(),
-            LinuxErrno::ENOMEM => "Out of memory".to_string(),
-            LinuxErrno::EACCES => "Permission denied".to_string(),
-            LinuxErrno::EFAULT => "Bad address".to_string(),
-            LinuxErrno::ENOTBLK => "Block device required".to_string(),
-            LinuxErrno::EBUSY => "Device or resource busy".to_string(),
-            LinuxErrno::EEXIST => "File exists".to_string(),
-            LinuxErrno::EXDEV => "Cross-device link".to_string(),
-            LinuxErrno::ENODEV => "No such device".to_string(),
-            LinuxErrno::ENOTDIR => "Not a directory".to_string(),
-            LinuxErrno::EISDIR => "Is a directory".to_string(),
-            LinuxErrno::EINVAL => "Invalid argument".to_string(),
-            LinuxErrno::ENFILE => "File table overflow".to_string(),
-            LinuxErrno::EMFILE => "Too many open files".to_string(),
-            LinuxErrno::ENOTTY => "Not a typewriter".to_string(),
-            LinuxErrno::ETXTBSY => "Text file busy".to_string(),
-            LinuxErrno::EFBIG => "File too large".to_string(),
-            LinuxErrno::ENOSPC => "No space left on device".to_string(),
-            LinuxErrno::ESPIPE => "Illegal seek".to_string(),
-            LinuxErrno::EROFS => "Read-only file system".to_string(),
-            LinuxErrno::EMLINK => "Too many links".to_string(),
-            LinuxErrno::EPIPE => "Broken pipe".to_string(),
-            LinuxErrno::EDOM => "Math argument out of domain of func".to_string(),
-            LinuxErrno::ERANGE => "Math result not representable".to_string(),
-            LinuxErrno::ENOSYS => "Function not implemented".to_string(),
-            LinuxErrno::ELOOP => "Too many symbolic links encountered".to_string(),
-            LinuxErrno::EADDRINUSE => "Address already in use".to_string(),
-            LinuxErrno::EPROTONOSUPPORT => "Protocol not supported".to_string(),
-            LinuxErrno::EOPNOTSUPP => "Operation not supported on transport endpoint".to_string(),
-            LinuxErrno::EPFNOSUPPORT => "Protocol family not supported".to_string(),
-            LinuxErrno::EAFNOSUPPORT => "Address family not supported by protocol".to_string(),
-            LinuxErrno::EADDRNOTAVAIL => "Cannot assign requested address".to_string(),
-            LinuxErrno::ENETDOWN => "Network is down".to_string(),
-            LinuxErrno::ENETUNREACH => "Network is unreachable".to_string(),
-            LinuxErrno::ENETRESET => "Network dropped connection because of reset".to_string(),
-            LinuxErrno::ECONNABORTED => "Software caused connection abort".to_string(),
-            LinuxErrno::ECONNRESET => "Connection reset by peer".to_string(),
-            LinuxErrno::ENOBUFS => "No buffer space available".to_string(),
-            LinuxErrno::EISCONN => "Transport endpoint is already connected".to_string(),
-            LinuxErrno::ENOTCONN => "Transport endpoint is not connected".to_string(),
-            LinuxErrno::EINPROGRESS => "Connection already in progress".to_string(),
-            LinuxErrno::ECONNREFUSED => "Connection refused".to_string(),
-            #[cfg(feature = "special_error")]
-            LinuxErrno::DOMAINCRASH => "Domain crash".to_string(),
-            #[cfg(feature = "special_error")]
-            LinuxErrno::EBLOCKING => "Blocking".to_string(),
-            LinuxErrno::EALREADY => "Port already in use".to_string(),
-        };
-        write!(f, "{}", str)
+        f.write_str(self.as_str())
     }
 }
 
-#[cfg(feature = "trick")]
-impl syscall_table::ToIsize for LinuxErrno {
-    fn to_isize(self) -> isize {
-        self as isize
+impl core::error::Error for LinuxErrno {}
+
+/// 内核内部使用的错误类型。
+///
+/// 除了 [`LinuxErrno`] 之外，还包含一些不应直接返回给用户态的内部状态，
+/// 在系统调用返回时通过 [`KernelError::user_errno`] 转换为用户可见的错误码。
+///
+/// 旧版本在 `special_error` feature 下提供的 `LinuxErrno::DOMAINCRASH` 与
+/// `LinuxErrno::EBLOCKING` 已被移除，请分别改用 [`KernelError::DomainCrash`] 与
+/// [`KernelError::WouldBlock`]，二者的 [`KernelError::raw`] 仍为 -255 与 -256。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KernelError {
+    /// 普通的 Linux 错误码
+    Errno(LinuxErrno),
+    /// 提供服务的 domain 崩溃
+    DomainCrash,
+    /// 操作需要阻塞等待
+    WouldBlock,
+    /// 被信号打断，若处理函数设置了 `SA_RESTART` 则重新执行 (`ERESTARTSYS`)
+    RestartSys,
+    /// 被信号打断，总是重新执行 (`ERESTARTNOINTR`)
+    RestartNoIntr,
+    /// 被信号打断，通过 `restart_syscall` 重新执行 (`ERESTART_RESTARTBLOCK`)
+    RestartRestartBlock,
+}
+
+impl KernelError {
+    /// `ERESTARTSYS` 在 Linux 内核中的值
+    pub const ERESTARTSYS: isize = -512;
+    /// `ERESTARTNOINTR` 在 Linux 内核中的值
+    pub const ERESTARTNOINTR: isize = -513;
+    /// `ERESTART_RESTARTBLOCK` 在 Linux 内核中的值
+    pub const ERESTART_RESTARTBLOCK: isize = -516;
+
+    /// 内核内部使用的原始值，可以在处理信号前暂存在返回值寄存器中
+    pub const fn raw(self) -> isize {
+        match self {
+            KernelError::Errno(errno) => errno.to_ret(),
+            KernelError::DomainCrash => -255,
+            KernelError::WouldBlock => -256,
+            KernelError::RestartSys => Self::ERESTARTSYS,
+            KernelError::RestartNoIntr => Self::ERESTARTNOINTR,
+            KernelError::RestartRestartBlock => Self::ERESTART_RESTARTBLOCK,
+        }
+    }
+
+    /// 由 [`KernelError::raw`] 的值还原，不属于内核错误的值返回 `None`
+    pub fn from_raw(raw: isize) -> Option<Self> {
+        match raw {
+            -255 => Some(KernelError::DomainCrash),
+            -256 => Some(KernelError::WouldBlock),
+            Self::ERESTARTSYS => Some(KernelError::RestartSys),
+            Self::ERESTARTNOINTR => Some(KernelError::RestartNoIntr),
+            Self::ERESTART_RESTARTBLOCK => Some(KernelError::RestartRestartBlock),
+            _ => LinuxErrno::try_from(raw).ok().map(KernelError::Errno),
+        }
+    }
+
+    /// 系统调用不重新执行而直接返回用户态时，用户看到的错误码
+    pub const fn user_errno(self) -> LinuxErrno {
+        match self {
+            KernelError::Errno(errno) => errno,
+            KernelError::DomainCrash => LinuxErrno::EIO,
+            KernelError::WouldBlock => LinuxErrno::EAGAIN,
+            KernelError::RestartSys
+            | KernelError::RestartNoIntr
+            | KernelError::RestartRestartBlock => LinuxErrno::EINTR,
+        }
+    }
+
+    /// 是否为需要在信号处理后重新执行系统调用的错误
+    pub const fn is_restart(self) -> bool {
+        matches!(
+            self,
+            KernelError::RestartSys | KernelError::RestartNoIntr | KernelError::RestartRestartBlock
+        )
+    }
+
+    /// 被信号打断后是否应重新执行系统调用。
+    ///
+    /// `action` 为将要执行的用户处理函数，没有处理函数时为 `None`。
+    /// 对于 [`KernelError::RestartRestartBlock`]，重新执行的是 `restart_syscall`。
+    /// 返回 `false` 时应以 [`KernelError::user_errno`] 返回用户态。
+    pub fn should_restart(self, action: Option<&SigAction>) -> bool {
+        match (self, action) {
+            (KernelError::RestartNoIntr, _) => true,
+            (KernelError::RestartSys, Some(action)) => {
+                action.flags.contains(SigActionFlags::SA_RESTART)
+            }
+            (KernelError::RestartSys | KernelError::RestartRestartBlock, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl From<LinuxErrno> for KernelError {
+    fn from(errno: LinuxErrno) -> Self {
+        KernelError::Errno(errno)
     }
 }
 
-pub const SYSCALL_GETCWD: usize = 17;
-pub const SYSCALL_DUP: usize = 23;
-pub const SYSCALL_DUP3: usize = 24;
-pub const SYSCALL_FCNTL: usize = 25;
-pub const SYSCALL_IOCTL: usize = 29;
-pub const SYSCALL_MKNODAT: usize = 33;
-pub const SYSCALL_MKDIRAT: usize = 34;
-pub const SYSCALL_UNLINKAT: usize = 35;
-pub const SYSCALL_LINKAT: usize = 37;
-pub const SYSCALL_UMOUNT2: usize = 39;
-pub const SYSCALL_MOUNT: usize = 40;
-pub const SYSCALL_STATFS: usize = 43;
-pub const SYSCALL_FTRUNCATE: usize = 46;
-pub const SYSCALL_FACCESSAT: usize = 48;
-pub const SYSCALL_CHDIR: usize = 49;
-pub const SYSCALL_FCHMOD: usize = 52;
-pub const SYSCALL_FCHMODAT: usize = 53;
-pub const SYSCALL_FCHOWN: usize = 55;
-pub const SYSCALL_OPENAT: usize = 56;
-pub const SYSCALL_CLOSE: usize = 57;
-pub const SYSCALL_PIPE2: usize = 59;
-pub const SYSCALL_GETDENTS64: usize = 61;
-pub const SYSCALL_LSEEK: usize = 62;
-pub const SYSCALL_READ: usize = 63;
-pub const SYSCALL_WRITE: usize = 64;
-pub const SYSCALL_READV: usize = 65;
-pub const SYSCALL_WRITEV: usize = 66;
-pub const SYSCALL_PREAD: usize = 67;
-pub const SYSCALL_PWRITE: usize = 68;
-pub const SYSCALL_SENDFILE: usize = 71;
-pub const SYSCALL_PSELECT6: usize = 72;
-pub const SYSCALL_PPOLL: usize = 73;
-pub const SYSCALL_READLINKAT: usize = 78;
-pub const SYSCALL_FSTATAT: usize = 79;
-pub const SYSCALL_FSTAT: usize = 80;
-pub const SYSCALL_SYNC: usize = 81;
-pub const SYSCALL_FSYNC: usize = 82;
-pub const SYSCALL_UTIMENSAT: usize = 88;
-pub const SYSCALL_EXIT: usize = 93;
-pub const SYSCALL_EXIT_GROUP: usize = 94;
-pub const SYSCALL_SET_TID_ADDRESS: usize = 96;
-pub const SYSCALL_FUTEX: usize = 98;
-pub const SYSCALL_SET_ROBUST_LIST: usize = 99;
-pub const SYSCALL_GET_ROBUST_LIST: usize = 100;
-pub const SYSCALL_NANOSLEEP: usize = 101;
-pub const SYSCALL_GETITIMER: usize = 102;
-pub const SYSCALL_SETITIMER: usize = 103;
-pub const SYSCALL_CLOCK_GETTIME: usize = 113;
-pub const SYSCALL_CLOCK_GETRES: usize = 114;
-pub const SYSCALL_CLOCK_NANOSLEEP: usize = 115;
-pub const SYSCALL_SYSLOG: usize = 116;
-pub const SYSCALL_YIELD: usize = 124;
-pub const SYSCALL_SCHED_SETPARAM: usize = 118;
-pub const SYSCALL_SCHED_GETPARAM: usize = 121;
-pub const SYSCALL_SCHED_SETAFFINITY: usize = 122;
-pub const SYSCALL_SCHED_GETAFFINITY: usize = 123;
-pub const SYSCALL_SCHED_GETSCHEDULER: usize = 120;
-pub const SYSCALL_SCHED_SETSCHEDULER: usize = 119;
-pub const SYSCALL_KILL: usize = 129;
-pub const SYSCALL_TKILL: usize = 130;
-pub const SYSCALL_SIGSUSPEND: usize = 133;
-pub const SYSCALL_SIGACTION: usize = 134;
-pub const SYSCALL_SIGPROCMASK: usize = 135;
-pub const SYSCALL_SIGTIMEDWAIT: usize = 137;
-pub const SYSCALL_SIGRETURN: usize = 139;
-pub const SYSCALL_TIMES: usize = 153;
-pub const SYSCALL_SETPGID: usize = 154;
-pub const SYSCALL_GETPGID: usize = 155;
-pub const SYSCALL_SETSID: usize = 157;
-pub const SYSCALL_UNAME: usize = 160;
-pub const SYSCALL_GETRUSAGE: usize = 165;
-pub const SYSCALL_UMASK: usize = 166;
-pub const SYSCALL_GET_TIME_OF_DAY: usize = 169;
-pub const SYSCALL_GETPID: usize = 172;
-pub const SYSCALL_GETPPID: usize = 173;
-pub const SYSCALL_GETUID: usize = 174;
-pub const SYSCALL_GETEUID: usize = 175;
-pub const SYSCALL_GETGID: usize = 176;
-pub const SYSCALL_GETEGID: usize = 177;
-pub const SYSCALL_GETTID: usize = 178;
-pub const SYSCALL_SYSINFO: usize = 179;
-pub const SYSCALL_SHMGET: usize = 194;
-pub const SYSCALL_SHAMCTL: usize = 195;
-pub const SYSCALL_SHAMAT: usize = 196;
-pub const SYSCALL_SHAMDT: usize = 197;
-pub const SYSCALL_SOCKET: usize = 198;
-pub const SYSCALL_BIND: usize = 200;
-pub const SYSCALL_LISTEN: usize = 201;
-pub const SYSCALL_ACCEPT: usize = 202;
-pub const SYSCALL_CONNECT: usize = 203;
-pub const SYSCALL_GETSOCKNAME: usize = 204;
-pub const SYSCALL_GETPEERNAME: usize = 205;
-pub const SYSCALL_SENDTO: usize = 206;
-pub const SYSCALL_RECVFROM: usize = 207;
-pub const SYSCALL_SETSOCKOPT: usize = 208;
-pub const SYSCALL_GETSOCKOPT: usize = 209;
-pub const SYSCALL_SBRK: usize = 213;
-pub const SYSCALL_BRK: usize = 214;
-pub const SYSCALL_MUNMAP: usize = 215;
-pub const SYSCALL_CLONE: usize = 220;
-pub const SYSCALL_EXECVE: usize = 221;
-pub const SYSCALL_MMAP: usize = 222;
-pub const SYSCALL_MPROTECT: usize = 226;
-pub const SYSCALL_MSYNC: usize = 227;
-pub const SYSCALL_MADVISE: usize = 233;
-pub const SYSCALL_WAIT4: usize = 260;
-pub const SYSCALL_PRLIMIT: usize = 261;
-pub const SYSCALL_RENAMEAT2: usize = 276;
-pub const SYSCALL_MEMBARRIER: usize = 283;
-pub const SYSCALL_FACCESSAT2: usize = 439;
-pub const SYSCALL_SHUTDOWN: usize = 210;
-pub const SYSCALL_COPY_FILE_RANGE: usize = 285;
-
-pub fn syscall_name(id: usize) -> &'static str {
-    match id {
-        SYSCALL_COPY_FILE_RANGE => "copy_file_range",
-        SYSCALL_FCHOWN => "fchown",
-        SYSCALL_SETSID => "setsid",
-        SYSCALL_SIGSUSPEND => "sigsuspend",
-        SYSCALL_MADVISE => "madvise",
-        SYSCALL_CLOCK_NANOSLEEP => "clock_nanosleep",
-        SYSCALL_CLOCK_GETRES => "clock_getres",
-        SYSCALL_SCHED_SETPARAM => "sched_setparam",
-        SYSCALL_SCHED_GETPARAM => "sched_getparam",
-        SYSCALL_SCHED_SETAFFINITY => "sched_setaffinity",
-        SYSCALL_SCHED_GETAFFINITY => "sched_getaffinity",
-        SYSCALL_SCHED_GETSCHEDULER => "sched_getscheduler",
-        SYSCALL_SCHED_SETSCHEDULER => "sched_setscheduler",
-        SYSCALL_SHUTDOWN => "shutdown",
-        SYSCALL_FCHMODAT => "fchmodat",
-        SYSCALL_FCHMOD => "fchmod",
-        SYSCALL_GETSOCKOPT => "getsockopt",
-        SYSCALL_MKNODAT => "mknodat",
-        SYSCALL_SHMGET => "shmget",
-        SYSCALL_SHAMCTL => "shamctl",
-        SYSCALL_SHAMAT => "shamat",
-        SYSCALL_SHAMDT => "shamdt",
-        SYSCALL_DUP => "dup",
-        SYSCALL_DUP3 => "dup3",
-        SYSCALL_GETCWD => "getcwd",
-        SYSCALL_FCNTL => "fcntl",
-        SYSCALL_IOCTL => "ioctl",
-        SYSCALL_MKDIRAT => "mkdirat",
-        SYSCALL_UNLINKAT => "unlinkat",
-        SYSCALL_LINKAT => "linkat",
-        SYSCALL_UMOUNT2 => "umount2",
-        SYSCALL_MOUNT => "mount",
-        SYSCALL_FACCESSAT => "faccessat",
-        SYSCALL_CHDIR => "chdir",
-        SYSCALL_OPENAT => "openat",
-        SYSCALL_CLOSE => "close",
-        SYSCALL_PIPE2 => "pipe2",
-        SYSCALL_GETDENTS64 => "getdents64",
-        SYSCALL_LSEEK => "lseek",
-        SYSCALL_READ => "read",
-        SYSCALL_WRITE => "write",
-        SYSCALL_READV => "readv",
-        SYSCALL_WRITEV => "writev",
-        SYSCALL_PREAD => "pread",
-        SYSCALL_PWRITE => "pwrite",
-        SYSCALL_SENDFILE => "sendfile",
-        SYSCALL_PSELECT6 => "pselect6",
-        SYSCALL_PPOLL => "ppoll",
-        SYSCALL_READLINKAT => "readlinkat",
-        SYSCALL_FSTATAT => "fstatat",
-        SYSCALL_FSTAT => "fstat",
-        SYSCALL_STATFS => "statfs",
-        SYSCALL_FTRUNCATE => "ftruncate",
-        SYSCALL_SYNC => "sync",
-        SYSCALL_FSYNC => "fsync",
-        SYSCALL_UTIMENSAT => "utimensat",
-        SYSCALL_EXIT => "exit",
-        SYSCALL_EXIT_GROUP => "exit_GROUP",
-        SYSCALL_SET_TID_ADDRESS => "set_tid_address",
-        SYSCALL_FUTEX => "futex",
-        SYSCALL_SET_ROBUST_LIST => "set_robust_list",
-        SYSCALL_GET_ROBUST_LIST => "get_robust_list",
-        SYSCALL_NANOSLEEP => "nanosleep",
-        SYSCALL_GETITIMER => "getitimer",
-        SYSCALL_SETITIMER => "setitimer",
-        SYSCALL_CLOCK_GETTIME => "clock_gettime",
-        SYSCALL_SYSLOG => "syslog",
-        SYSCALL_YIELD => "yield",
-        SYSCALL_KILL => "kill",
-        SYSCALL_TKILL => "tkill",
-        SYSCALL_SIGACTION => "sigaction",
-        SYSCALL_SIGPROCMASK => "sigprocmask",
-        SYSCALL_SIGTIMEDWAIT => "sigtimedwait",
-        SYSCALL_SIGRETURN => "sigreturn",
-        SYSCALL_TIMES => "times",
-        SYSCALL_SETPGID => "setpgid",
-        SYSCALL_GETPGID => "getpgid",
-        SYSCALL_UNAME => "uname",
-        SYSCALL_GETRUSAGE => "getrusage",
-        SYSCALL_UMASK => "umask",
-        SYSCALL_GET_TIME_OF_DAY => "get_time_of_day",
-        SYSCALL_GETPID => "getpid",
-        SYSCALL_GETPPID => "getppid",
-        SYSCALL_GETUID => "getuid",
-        SYSCALL_GETEUID => "geteuid",
-        SYSCALL_GETGID => "getgid",
-        SYSCALL_GETEGID => "getegid",
-        SYSCALL_GETTID => "gettid",
-        SYSCALL_SYSINFO => "sysinfo",
-        SYSCALL_SOCKET => "socket",
-        SYSCALL_BIND => "bind",
-        SYSCALL_LISTEN => "listen",
-        SYSCALL_ACCEPT => "accept",
-        SYSCALL_CONNECT => "connect",
-        SYSCALL_GETSOCKNAME => "getsockname",
-        SYSCALL_GETPEERNAME => "getpeername",
-        SYSCALL_SENDTO => "sendto",
-        SYSCALL_RECVFROM => "recvfrom",
-        SYSCALL_SETSOCKOPT => "setsockopt",
-        SYSCALL_SBRK => "sbrk",
-        SYSCALL_BRK => "brk",
-        SYSCALL_MUNMAP => "munmap",
-        SYSCALL_CLONE => "clone",
-        SYSCALL_EXECVE => "execve",
-        SYSCALL_MMAP => "mmap",
-        SYSCALL_MPROTECT => "mprotect",
-        SYSCALL_MSYNC => "msync",
-        SYSCALL_WAIT4 => "wait4",
-        SYSCALL_PRLIMIT => "prlimit",
-        SYSCALL_RENAMEAT2 => "renameat2",
-        SYSCALL_FACCESSAT2 => "faccessat2",
-        SYSCALL_MEMBARRIER => "membarrier",
-        _ => "unknown",
+impl Display for KernelError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            KernelError::Errno(errno) => errno.fmt(f),
+            KernelError::DomainCrash => f.write_str("Domain crash"),
+            KernelError::WouldBlock => f.write_str("Operation would block"),
+            KernelError::RestartSys
+            | KernelError::RestartNoIntr
+            | KernelError::RestartRestartBlock => f.write_str("Interrupted system call"),
+        }
+    }
+}
+
+impl core::error::Error for KernelError {}
+
+impl IntoRet for Result<usize, KernelError> {
+    fn into_ret(self) -> isize {
+        self.map_err(KernelError::user_errno).into_ret()
+    }
+}
+
+#[cfg(feature = "trick")]
+impl syscall_table::ToIsize for LinuxErrno {
+    fn to_isize(self) -> isize {
+        self.to_ret()
     }
 }
 
 #[repr(C)]
-#[derive(Debug, Copy, Clone, Pod)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Pod)]
 pub struct RLimit64 {
     /// 软上限
     pub rlim_cur: u64,
@@ -390,21 +621,562 @@ impl RLimit64 {
             rlim_max: max,
         }
     }
+
+    /// 软上限是否不超过硬上限
+    pub fn is_valid(&self) -> bool {
+        self.rlim_cur <= self.rlim_max
+    }
+
+    /// 软上限是否为 [`RLIM_INFINITY`]
+    pub fn is_infinite(&self) -> bool {
+        self.rlim_cur == RLIM_INFINITY
+    }
 }
 
 impl Default for RLimit64 {
     fn default() -> Self {
         Self {
-            rlim_cur: u64::MAX,
-            rlim_max: u64::MAX,
+            rlim_cur: RLIM_INFINITY,
+            rlim_max: RLIM_INFINITY,
         }
     }
 }
 
+/// 资源上限的数量，即 `RLIM_NLIMITS`
+pub const RLIM_NLIMITS: usize = 16;
+/// 表示没有限制的资源上限
+pub const RLIM_INFINITY: u64 = u64::MAX;
+/// 文件描述符上限的最大值，对应 Linux 的 `sysctl_nr_open` 默认值
+pub const NR_OPEN: u64 = 1024 * 1024;
+
 #[repr(usize)]
 #[derive(Eq, PartialEq, Debug, Copy, Clone, IntEnum)]
 pub enum PrLimitResType {
+    /// CPU 时间，单位为秒
+    RlimitCpu = 0,
+    /// 可创建文件的最大长度
+    RlimitFsize = 1,
+    /// 数据段的最大长度
+    RlimitData = 2,
+    /// 栈的最大长度
     RlimitStack = 3,
+    /// core 文件的最大长度
+    RlimitCore = 4,
+    /// 常驻内存的最大长度
+    RlimitRss = 5,
+    /// 可创建的最大进程数
+    RlimitNproc = 6,
+    /// 可打开的最大文件描述符数加一
     RlimitNofile = 7,
+    /// 可锁定的最大内存
+    RlimitMemlock = 8,
+    /// 地址空间的最大长度
     RlimitAs = 9,
+    /// 可持有的最大文件锁数
+    RlimitLocks = 10,
+    /// 可排队的最大信号数
+    RlimitSigpending = 11,
+    /// POSIX 消息队列的最大字节数
+    RlimitMsgqueue = 12,
+    /// nice 值的上限，实际为 20 - limit
+    RlimitNice = 13,
+    /// 实时优先级的上限
+    RlimitRtprio = 14,
+    /// 实时任务不阻塞时可运行的 CPU 时间，单位为微秒
+    RlimitRttime = 15,
+}
+
+impl PrLimitResType {
+    /// 资源的符号名，如 `"RLIMIT_NOFILE"`
+    pub const fn name(self) -> &'static str {
+        match self {
+            PrLimitResType::RlimitCpu => "RLIMIT_CPU",
+            PrLimitResType::RlimitFsize => "RLIMIT_FSIZE",
+            PrLimitResType::RlimitData => "RLIMIT_DATA",
+            PrLimitResType::RlimitStack => "RLIMIT_STACK",
+            PrLimitResType::RlimitCore => "RLIMIT_CORE",
+            PrLimitResType::RlimitRss => "RLIMIT_RSS",
+            PrLimitResType::RlimitNproc => "RLIMIT_NPROC",
+            PrLimitResType::RlimitNofile => "RLIMIT_NOFILE",
+            PrLimitResType::RlimitMemlock => "RLIMIT_MEMLOCK",
+            PrLimitResType::RlimitAs => "RLIMIT_AS",
+            PrLimitResType::RlimitLocks => "RLIMIT_LOCKS",
+            PrLimitResType::RlimitSigpending => "RLIMIT_SIGPENDING",
+            PrLimitResType::RlimitMsgqueue => "RLIMIT_MSGQUEUE",
+            PrLimitResType::RlimitNice => "RLIMIT_NICE",
+            PrLimitResType::RlimitRtprio => "RLIMIT_RTPRIO",
+            PrLimitResType::RlimitRttime => "RLIMIT_RTTIME",
+        }
+    }
+}
+
+/// 进程的资源上限表，`getrlimit`/`setrlimit`/`prlimit64` 的实现基础
+#[derive(Debug, Copy, Clone)]
+pub struct RLimits {
+    limits: [RLimit64; RLIM_NLIMITS],
+}
+
+impl RLimits {
+    /// 创建一个使用 Linux 默认值 (`INIT_RLIMITS`) 的资源上限表。
+    ///
+    /// Linux 在启动时根据内存大小计算 `RLIMIT_NPROC` 与 `RLIMIT_SIGPENDING`，
+    /// 这里将其设为 [`RLIM_INFINITY`]，内核可以在创建 init 进程后自行降低。
+    pub const fn new() -> Self {
+        let mut limits = [RLimit64 {
+            rlim_cur: RLIM_INFINITY,
+            rlim_max: RLIM_INFINITY,
+        }; RLIM_NLIMITS];
+        limits[PrLimitResType::RlimitStack as usize].rlim_cur = 8 * 1024 * 1024;
+        limits[PrLimitResType::RlimitCore as usize].rlim_cur = 0;
+        limits[PrLimitResType::RlimitNofile as usize] = RLimit64 {
+            rlim_cur: 1024,
+            rlim_max: 4096,
+        };
+        limits[PrLimitResType::RlimitMemlock as usize] = RLimit64 {
+            rlim_cur: 8 * 1024 * 1024,
+            rlim_max: 8 * 1024 * 1024,
+        };
+        limits[PrLimitResType::RlimitMsgqueue as usize] = RLimit64 {
+            rlim_cur: 819200,
+            rlim_max: 819200,
+        };
+        limits[PrLimitResType::RlimitNice as usize] = RLimit64 {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        limits[PrLimitResType::RlimitRtprio as usize] = RLimit64 {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        Self { limits }
+    }
+
+    /// 获取某项资源的上限
+    pub fn get(&self, resource: PrLimitResType) -> RLimit64 {
+        self.limits[resource as usize]
+    }
+
+    /// 设置某项资源的上限，成功时返回原来的值。
+    ///
+    /// - 软上限大于硬上限时返回 `EINVAL`；
+    /// - 非特权 (`privileged` 为 `false`) 的调用者提高硬上限时返回 `EPERM`；
+    /// - `RLIMIT_NOFILE` 的硬上限超过 [`NR_OPEN`] 时返回 `EPERM`。
+    pub fn set(
+        &mut self,
+        resource: PrLimitResType,
+        new: RLimit64,
+        privileged: bool,
+    ) -> Result<RLimit64, LinuxErrno> {
+        let old = self.get(resource);
+        if !new.is_valid() {
+            return Err(LinuxErrno::EINVAL);
+        }
+        if new.rlim_max > old.rlim_max && !privileged {
+            return Err(LinuxErrno::EPERM);
+        }
+        if resource == PrLimitResType::RlimitNofile && new.rlim_max > NR_OPEN {
+            return Err(LinuxErrno::EPERM);
+        }
+        self.limits[resource as usize] = new;
+        Ok(old)
+    }
+
+    /// 按 `prlimit64` 的语义获取并可选地设置资源上限，返回原来的值
+    pub fn prlimit(
+        &mut self,
+        resource: PrLimitResType,
+        new: Option<RLimit64>,
+        privileged: bool,
+    ) -> Result<RLimit64, LinuxErrno> {
+        match new {
+            Some(new) => self.set(resource, new, privileged),
+            None => Ok(self.get(resource)),
+        }
+    }
+}
+
+impl Default for RLimits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+
+    /// glibc `strerror` 给出的描述，按错误码排列，41 与 58 未被使用
+    const GLIBC_STRERROR: &[(isize, &str, &str)] = &[
+        (1, "EPERM", "Operation not permitted"),
+        (2, "ENOENT", "No such file or directory"),
+        (3, "ESRCH", "No such process"),
+        (4, "EINTR", "Interrupted system call"),
+        (5, "EIO", "Input/output error"),
+        (6, "ENXIO", "No such device or address"),
+        (7, "E2BIG", "Argument list too long"),
+        (8, "ENOEXEC", "Exec format error"),
+        (9, "EBADF", "Bad file descriptor"),
+        (10, "ECHILD", "No child processes"),
+        (11, "EAGAIN", "Resource temporarily unavailable"),
+        (12, "ENOMEM", "Cannot allocate memory"),
+        (13, "EACCES", "Permission denied"),
+        (14, "EFAULT", "Bad address"),
+        (15, "ENOTBLK", "Block device required"),
+        (16, "EBUSY", "Device or resource busy"),
+        (17, "EEXIST", "File exists"),
+        (18, "EXDEV", "Invalid cross-device link"),
+        (19, "ENODEV", "No such device"),
+        (20, "ENOTDIR", "Not a directory"),
+        (21, "EISDIR", "Is a directory"),
+        (22, "EINVAL", "Invalid argument"),
+        (23, "ENFILE", "Too many open files in system"),
+        (24, "EMFILE", "Too many open files"),
+        (25, "ENOTTY", "Inappropriate ioctl for device"),
+        (26, "ETXTBSY", "Text file busy"),
+        (27, "EFBIG", "File too large"),
+        (28, "ENOSPC", "No space left on device"),
+        (29, "ESPIPE", "Illegal seek"),
+        (30, "EROFS", "Read-only file system"),
+        (31, "EMLINK", "Too many links"),
+        (32, "EPIPE", "Broken pipe"),
+        (33, "EDOM", "Numerical argument out of domain"),
+        (34, "ERANGE", "Numerical result out of range"),
+        (35, "EDEADLK", "Resource deadlock avoided"),
+        (36, "ENAMETOOLONG", "File name too long"),
+        (37, "ENOLCK", "No locks available"),
+        (38, "ENOSYS", "Function not implemented"),
+        (39, "ENOTEMPTY", "Directory not empty"),
+        (40, "ELOOP", "Too many levels of symbolic links"),
+        (42, "ENOMSG", "No message of desired type"),
+        (43, "EIDRM", "Identifier removed"),
+        (44, "ECHRNG", "Channel number out of range"),
+        (45, "EL2NSYNC", "Level 2 not synchronized"),
+        (46, "EL3HLT", "Level 3 halted"),
+        (47, "EL3RST", "Level 3 reset"),
+        (48, "ELNRNG", "Link number out of range"),
+        (49, "EUNATCH", "Protocol driver not attached"),
+        (50, "ENOCSI", "No CSI structure available"),
+        (51, "EL2HLT", "Level 2 halted"),
+        (52, "EBADE", "Invalid exchange"),
+        (53, "EBADR", "Invalid request descriptor"),
+        (54, "EXFULL", "Exchange full"),
+        (55, "ENOANO", "No anode"),
+        (56, "EBADRQC", "Invalid request code"),
+        (57, "EBADSLT", "Invalid slot"),
+        (59, "EBFONT", "Bad font file format"),
+        (60, "ENOSTR", "Device not a stream"),
+        (61, "ENODATA", "No data available"),
+        (62, "ETIME", "Timer expired"),
+        (63, "ENOSR", "Out of streams resources"),
+        (64, "ENONET", "Machine is not on the network"),
+        (65, "ENOPKG", "Package not installed"),
+        (66, "EREMOTE", "Object is remote"),
+        (67, "ENOLINK", "Link has been severed"),
+        (68, "EADV", "Advertise error"),
+        (69, "ESRMNT", "Srmount error"),
+        (70, "ECOMM", "Communication error on send"),
+        (71, "EPROTO", "Protocol error"),
+        (72, "EMULTIHOP", "Multihop attempted"),
+        (73, "EDOTDOT", "RFS specific error"),
+        (74, "EBADMSG", "Bad message"),
+        (75, "EOVERFLOW", "Value too large for defined data type"),
+        (76, "ENOTUNIQ", "Name not unique on network"),
+        (77, "EBADFD", "File descriptor in bad state"),
+        (78, "EREMCHG", "Remote address changed"),
+        (79, "ELIBACC", "Can not access a needed shared library"),
+        (80, "ELIBBAD", "Accessing a corrupted shared library"),
+        (81, "ELIBSCN", ".lib section in a.out corrupted"),
+        (
+            82,
+            "ELIBMAX",
+            "Attempting to link in too many shared libraries",
+        ),
+        (83, "ELIBEXEC", "Cannot exec a shared library directly"),
+        (
+            84,
+            "EILSEQ",
+            "Invalid or incomplete multibyte or wide character",
+        ),
+        (
+            85,
+            "ERESTART",
+            "Interrupted system call should be restarted",
+        ),
+        (86, "ESTRPIPE", "Streams pipe error"),
+        (87, "EUSERS", "Too many users"),
+        (88, "ENOTSOCK", "Socket operation on non-socket"),
+        (89, "EDESTADDRREQ", "Destination address required"),
+        (90, "EMSGSIZE", "Message too long"),
+        (91, "EPROTOTYPE", "Protocol wrong type for socket"),
+        (92, "ENOPROTOOPT", "Protocol not available"),
+        (93, "EPROTONOSUPPORT", "Protocol not supported"),
+        (94, "ESOCKTNOSUPPORT", "Socket type not supported"),
+        (95, "EOPNOTSUPP", "Operation not supported"),
+        (96, "EPFNOSUPPORT", "Protocol family not supported"),
+        (
+            97,
+            "EAFNOSUPPORT",
+            "Address family not supported by protocol",
+        ),
+        (98, "EADDRINUSE", "Address already in use"),
+        (99, "EADDRNOTAVAIL", "Cannot assign requested address"),
+        (100, "ENETDOWN", "Network is down"),
+        (101, "ENETUNREACH", "Network is unreachable"),
+        (102, "ENETRESET", "Network dropped connection on reset"),
+        (103, "ECONNABORTED", "Software caused connection abort"),
+        (104, "ECONNRESET", "Connection reset by peer"),
+        (105, "ENOBUFS", "No buffer space available"),
+        (106, "EISCONN", "Transport endpoint is already connected"),
+        (107, "ENOTCONN", "Transport endpoint is not connected"),
+        (
+            108,
+            "ESHUTDOWN",
+            "Cannot send after transport endpoint shutdown",
+        ),
+        (109, "ETOOMANYREFS", "Too many references: cannot splice"),
+        (110, "ETIMEDOUT", "Connection timed out"),
+        (111, "ECONNREFUSED", "Connection refused"),
+        (112, "EHOSTDOWN", "Host is down"),
+        (113, "EHOSTUNREACH", "No route to host"),
+        (114, "EALREADY", "Operation already in progress"),
+        (115, "EINPROGRESS", "Operation now in progress"),
+        (116, "ESTALE", "Stale file handle"),
+        (117, "EUCLEAN", "Structure needs cleaning"),
+        (118, "ENOTNAM", "Not a XENIX named type file"),
+        (119, "ENAVAIL", "No XENIX semaphores available"),
+        (120, "EISNAM", "Is a named type file"),
+        (121, "EREMOTEIO", "Remote I/O error"),
+        (122, "EDQUOT", "Disk quota exceeded"),
+        (123, "ENOMEDIUM", "No medium found"),
+        (124, "EMEDIUMTYPE", "Wrong medium type"),
+        (125, "ECANCELED", "Operation canceled"),
+        (126, "ENOKEY", "Required key not available"),
+        (127, "EKEYEXPIRED", "Key has expired"),
+        (128, "EKEYREVOKED", "Key has been revoked"),
+        (129, "EKEYREJECTED", "Key was rejected by service"),
+        (130, "EOWNERDEAD", "Owner died"),
+        (131, "ENOTRECOVERABLE", "State not recoverable"),
+        (132, "ERFKILL", "Operation not possible due to RF-kill"),
+        (133, "EHWPOISON", "Memory page has hardware error"),
+    ];
+
+    #[test]
+    fn errno_numbers_names_and_messages() {
+        assert_eq!(GLIBC_STRERROR.len(), 131);
+        for &(num, name, msg) in GLIBC_STRERROR {
+            let errno = LinuxErrno::try_from(-num).unwrap();
+            assert_eq!(errno as isize, -num);
+            assert_eq!(format!("{:?}", errno), name);
+            assert_eq!(errno.name(), name);
+            assert_eq!(errno.as_str(), msg, "{}", name);
+        }
+        for num in [0, 41, 58, 134] {
+            assert!(LinuxErrno::try_from(-num).is_err());
+        }
+    }
+
+    #[test]
+    fn error_value_range() {
+        assert!(LinuxErrno::is_error_value(-1));
+        assert!(LinuxErrno::is_error_value(-(MAX_ERRNO as isize)));
+        assert!(!LinuxErrno::is_error_value(-(MAX_ERRNO as isize) - 1));
+        assert!(!LinuxErrno::is_error_value(isize::MIN));
+        assert!(!LinuxErrno::is_error_value(0));
+        assert!(!LinuxErrno::is_error_value(isize::MAX));
+    }
+
+    #[test]
+    fn decode_ret() {
+        assert_eq!(LinuxErrno::from_ret(0), Ok(0));
+        assert_eq!(LinuxErrno::from_ret(42), Ok(42));
+        assert_eq!(LinuxErrno::from_ret(-2), Err(LinuxErrno::ENOENT));
+        assert_eq!(LinuxErrno::from_ret(-133), Err(LinuxErrno::EHWPOISON));
+        // 错误区间内未定义的值
+        for ret in [-41, -58, -134, -512, -4095] {
+            assert_eq!(LinuxErrno::from_ret(ret), Err(LinuxErrno::EINVAL));
+        }
+        // 错误区间之外的负值是正常的返回值，如 mmap 返回的高地址
+        assert_eq!(LinuxErrno::from_ret(-4096), Ok(-4096isize as usize));
+        let addr = usize::MAX - 0x1fff;
+        assert_eq!(LinuxErrno::from_ret(addr as isize), Ok(addr));
+        assert_eq!(LinuxErrno::from_ret(isize::MIN), Ok(isize::MIN as usize));
+    }
+
+    #[test]
+    fn encode_ret() {
+        assert_eq!(LinuxErrno::EPERM.to_ret(), -1);
+        assert_eq!(LinuxErrno::EHWPOISON.to_ret(), -133);
+        let round_trip =
+            |result: Result<usize, LinuxErrno>| LinuxErrno::from_ret(result.into_ret());
+        assert_eq!(Err::<usize, _>(LinuxErrno::EBADF).into_ret(), -9);
+        assert_eq!(Ok::<_, LinuxErrno>(7).into_ret(), 7);
+        for value in [0, 1, usize::MAX - 0x1fff, usize::MAX - MAX_ERRNO] {
+            assert_eq!(round_trip(Ok(value)), Ok(value));
+        }
+        for errno in [LinuxErrno::EPERM, LinuxErrno::EAGAIN, LinuxErrno::EHWPOISON] {
+            assert_eq!(round_trip(Err(errno)), Err(errno));
+        }
+    }
+
+    #[test]
+    fn kernel_error_raw() {
+        let errors = [
+            KernelError::Errno(LinuxErrno::EINTR),
+            KernelError::DomainCrash,
+            KernelError::WouldBlock,
+            KernelError::RestartSys,
+            KernelError::RestartNoIntr,
+            KernelError::RestartRestartBlock,
+        ];
+        for error in errors {
+            assert_eq!(KernelError::from_raw(error.raw()), Some(error));
+        }
+        assert_eq!(KernelError::DomainCrash.raw(), -255);
+        assert_eq!(KernelError::WouldBlock.raw(), -256);
+        assert_eq!(KernelError::from_raw(0), None);
+        assert_eq!(KernelError::from_raw(-514), None);
+        assert_eq!(KernelError::from_raw(-4095), None);
+    }
+
+    #[test]
+    fn kernel_error_user_errno() {
+        assert_eq!(
+            KernelError::Errno(LinuxErrno::ENOENT).user_errno(),
+            LinuxErrno::ENOENT
+        );
+        assert_eq!(KernelError::DomainCrash.user_errno(), LinuxErrno::EIO);
+        assert_eq!(KernelError::WouldBlock.user_errno(), LinuxErrno::EAGAIN);
+        for error in [
+            KernelError::RestartSys,
+            KernelError::RestartNoIntr,
+            KernelError::RestartRestartBlock,
+        ] {
+            assert!(error.is_restart());
+            assert_eq!(error.user_errno(), LinuxErrno::EINTR);
+            assert_eq!(Err(error).into_ret(), -4);
+        }
+        assert!(!KernelError::WouldBlock.is_restart());
+        assert_eq!(Err::<usize, _>(KernelError::DomainCrash).into_ret(), -5);
+        assert_eq!(Ok::<_, KernelError>(3).into_ret(), 3);
+    }
+
+    #[test]
+    fn kernel_error_should_restart() {
+        let mut handler = SigAction::empty();
+        handler.handler = 0x1000;
+        let mut restart = handler;
+        restart.flags = SigActionFlags::SA_RESTART;
+
+        assert!(KernelError::RestartSys.should_restart(None));
+        assert!(!KernelError::RestartSys.should_restart(Some(&handler)));
+        assert!(KernelError::RestartSys.should_restart(Some(&restart)));
+
+        assert!(KernelError::RestartNoIntr.should_restart(None));
+        assert!(KernelError::RestartNoIntr.should_restart(Some(&handler)));
+        assert!(KernelError::RestartNoIntr.should_restart(Some(&restart)));
+
+        // 有处理函数时 restart_syscall 不会被执行，即使设置了 SA_RESTART
+        assert!(KernelError::RestartRestartBlock.should_restart(None));
+        assert!(!KernelError::RestartRestartBlock.should_restart(Some(&handler)));
+        assert!(!KernelError::RestartRestartBlock.should_restart(Some(&restart)));
+
+        for error in [
+            KernelError::Errno(LinuxErrno::EINTR),
+            KernelError::DomainCrash,
+            KernelError::WouldBlock,
+        ] {
+            assert!(!error.should_restart(None));
+            assert!(!error.should_restart(Some(&restart)));
+        }
+    }
+
+    #[test]
+    fn rlimits_default() {
+        let limits = RLimits::default();
+        assert_eq!(
+            limits.get(PrLimitResType::RlimitNofile),
+            RLimit64::new(1024, 4096)
+        );
+        assert_eq!(
+            limits.get(PrLimitResType::RlimitStack),
+            RLimit64::new(8 * 1024 * 1024, RLIM_INFINITY)
+        );
+        assert!(limits.get(PrLimitResType::RlimitCpu).is_infinite());
+    }
+
+    #[test]
+    fn rlimits_set_errors() {
+        let mut limits = RLimits::new();
+        let nofile = PrLimitResType::RlimitNofile;
+        // 软上限大于硬上限
+        assert_eq!(
+            limits.set(nofile, RLimit64::new(2048, 1024), true),
+            Err(LinuxErrno::EINVAL)
+        );
+        // 非特权进程不能提高硬上限
+        assert_eq!(
+            limits.set(nofile, RLimit64::new(1024, 8192), false),
+            Err(LinuxErrno::EPERM)
+        );
+        // 即使是特权进程也不能超过 NR_OPEN
+        assert_eq!(
+            limits.set(nofile, RLimit64::new(1024, NR_OPEN + 1), true),
+            Err(LinuxErrno::EPERM)
+        );
+        assert_eq!(limits.get(nofile), RLimit64::new(1024, 4096));
+        // 其他资源没有 NR_OPEN 的限制
+        let stack = PrLimitResType::RlimitStack;
+        assert!(limits
+            .set(stack, RLimit64::new(NR_OPEN + 1, NR_OPEN + 1), false)
+            .is_ok());
+    }
+
+    #[test]
+    fn rlimits_set_hard_limit() {
+        let mut limits = RLimits::new();
+        let nofile = PrLimitResType::RlimitNofile;
+        assert_eq!(
+            limits.set(nofile, RLimit64::new(NR_OPEN, NR_OPEN), true),
+            Ok(RLimit64::new(1024, 4096))
+        );
+        // 非特权进程可以降低硬上限，但之后不能再提高
+        assert_eq!(
+            limits.set(nofile, RLimit64::new(256, 512), false),
+            Ok(RLimit64::new(NR_OPEN, NR_OPEN))
+        );
+        assert_eq!(
+            limits.set(nofile, RLimit64::new(512, 512), false),
+            Ok(RLimit64::new(256, 512))
+        );
+        assert_eq!(
+            limits.set(nofile, RLimit64::new(512, 513), false),
+            Err(LinuxErrno::EPERM)
+        );
+    }
+
+    #[test]
+    fn rlimits_prlimit() {
+        let mut limits = RLimits::new();
+        let core = PrLimitResType::RlimitCore;
+        assert_eq!(
+            limits.prlimit(core, None, false),
+            Ok(RLimit64::new(0, RLIM_INFINITY))
+        );
+        assert_eq!(
+            limits.prlimit(core, Some(RLimit64::new(4096, 8192)), false),
+            Ok(RLimit64::new(0, RLIM_INFINITY))
+        );
+        assert_eq!(
+            limits.prlimit(core, None, false),
+            Ok(RLimit64::new(4096, 8192))
+        );
+        // 失败时不修改原来的值
+        assert_eq!(
+            limits.prlimit(core, Some(RLimit64::new(1, 0)), true),
+            Err(LinuxErrno::EINVAL)
+        );
+        assert_eq!(limits.get(core), RLimit64::new(4096, 8192));
+    }
 }
@@ -1,6 +1,10 @@
-use core::{fmt::Debug, mem::offset_of};
+use core::{
+    fmt::{Debug, Display, Write},
+    mem::offset_of,
+    ops::Range,
+};
 
-use crate::epoll::EpollEventType;
+use crate::{epoll::EpollEventType, signal::SignalNumber, time::TimeSpec, LinuxErrno};
 use bitflags::bitflags;
 use int_enum::IntEnum;
 use pod::Pod;
@@ -33,38 +37,270 @@ pub enum Fcntl64Cmd {
     F_GETFL = 3,
     /// 设置 flags 信息
     F_SETFL = 4,
-    /// 复制 fd，然后设置 cloexec 信息，即 exec 成功时删除该 fd
+    /// 检查记录锁是否可以加上，参数为 [`Flock`]
     GETLK = 5,
+    /// 加记录锁或解锁，冲突时返回 EAGAIN
     SETLK = 6,
+    /// 加记录锁，冲突时等待
     SETLKW = 7,
+    /// 设置接收 SIGIO 的进程，负数表示进程组
     SETOWN = 8,
+    /// 获取接收 SIGIO 的进程
     GETOWN = 9,
+    /// 设置 I/O 就绪时发送的信号，0 表示 SIGIO
     SETSIG = 10,
+    /// 获取 I/O 就绪时发送的信号
     GETSIG = 11,
+    /// 设置接收 SIGIO 的线程、进程或进程组，参数为 [`FOwnerEx`]
     SETOWN_EX = 15,
+    /// 获取接收 SIGIO 的线程、进程或进程组
     GETOWN_EX = 16,
+    /// 获取设置 owner 的用户的 uid 与 euid
     GETOWNER_UIDS = 17,
+    /// 与 GETLK 相同，但锁属于打开的文件描述
     OFD_GETLK = 36,
+    /// 与 SETLK 相同，但锁属于打开的文件描述
     OFD_SETLK = 37,
+    /// 与 SETLKW 相同，但锁属于打开的文件描述
     OFD_SETLKW = 38,
+    /// 设置租约
     SETLEASE = 1024,
+    /// 获取租约
     GETLEASE = 1025,
+    /// 目录变化时发送信号
     NOTIFY = 1026,
+    /// 判断参数与该 fd 是否指向同一个打开的文件描述
+    DUPFD_QUERY = 1027,
+    /// 判断文件是否由本次 open 创建
+    CREATED_QUERY = 1028,
+    /// 取消租约
     CANCELLK = 1029,
+    /// 复制 fd，然后设置 cloexec 信息，即 exec 成功时删除该 fd
     F_DUPFD_CLOEXEC = 1030,
+    /// 设置管道容量
     SETPIPE_SZ = 1031,
+    /// 获取管道容量
     GETPIPE_SZ = 1032,
+    /// 为 memfd 添加封印，参数为 [`SealFlags`]
     ADD_SEALS = 1033,
+    /// 获取 memfd 的封印
     GET_SEALS = 1034,
+    /// 获取 inode 的读写提示
     GET_RW_HINT = 1035,
+    /// 设置 inode 的读写提示
     SET_RW_HINT = 1036,
+    /// 获取打开的文件的读写提示
     GET_FILE_RW_HINT = 1037,
+    /// 设置打开的文件的读写提示
     SET_FILE_RW_HINT = 1038,
-    Unknown = 0xffff,
+}
+
+impl Fcntl64Cmd {
+    /// C 语言中的名字，如 `"F_GETLK"`
+    pub const fn name(self) -> &'static str {
+        match self {
+            Fcntl64Cmd::F_DUPFD => "F_DUPFD",
+            Fcntl64Cmd::F_GETFD => "F_GETFD",
+            Fcntl64Cmd::F_SETFD => "F_SETFD",
+            Fcntl64Cmd::F_GETFL => "F_GETFL",
+            Fcntl64Cmd::F_SETFL => "F_SETFL",
+            Fcntl64Cmd::GETLK => "F_GETLK",
+            Fcntl64Cmd::SETLK => "F_SETLK",
+            Fcntl64Cmd::SETLKW => "F_SETLKW",
+            Fcntl64Cmd::SETOWN => "F_SETOWN",
+            Fcntl64Cmd::GETOWN => "F_GETOWN",
+            Fcntl64Cmd::SETSIG => "F_SETSIG",
+            Fcntl64Cmd::GETSIG => "F_GETSIG",
+            Fcntl64Cmd::SETOWN_EX => "F_SETOWN_EX",
+            Fcntl64Cmd::GETOWN_EX => "F_GETOWN_EX",
+            Fcntl64Cmd::GETOWNER_UIDS => "F_GETOWNER_UIDS",
+            Fcntl64Cmd::OFD_GETLK => "F_OFD_GETLK",
+            Fcntl64Cmd::OFD_SETLK => "F_OFD_SETLK",
+            Fcntl64Cmd::OFD_SETLKW => "F_OFD_SETLKW",
+            Fcntl64Cmd::SETLEASE => "F_SETLEASE",
+            Fcntl64Cmd::GETLEASE => "F_GETLEASE",
+            Fcntl64Cmd::NOTIFY => "F_NOTIFY",
+            Fcntl64Cmd::DUPFD_QUERY => "F_DUPFD_QUERY",
+            Fcntl64Cmd::CREATED_QUERY => "F_CREATED_QUERY",
+            Fcntl64Cmd::CANCELLK => "F_CANCELLK",
+            Fcntl64Cmd::F_DUPFD_CLOEXEC => "F_DUPFD_CLOEXEC",
+            Fcntl64Cmd::SETPIPE_SZ => "F_SETPIPE_SZ",
+            Fcntl64Cmd::GETPIPE_SZ => "F_GETPIPE_SZ",
+            Fcntl64Cmd::ADD_SEALS => "F_ADD_SEALS",
+            Fcntl64Cmd::GET_SEALS => "F_GET_SEALS",
+            Fcntl64Cmd::GET_RW_HINT => "F_GET_RW_HINT",
+            Fcntl64Cmd::SET_RW_HINT => "F_SET_RW_HINT",
+            Fcntl64Cmd::GET_FILE_RW_HINT => "F_GET_FILE_RW_HINT",
+            Fcntl64Cmd::SET_FILE_RW_HINT => "F_SET_FILE_RW_HINT",
+        }
+    }
+}
+
+/// `F_GETFD`/`F_SETFD` 使用的 close-on-exec 标志
+pub const FD_CLOEXEC: usize = 1;
+
+/// 记录锁与租约的类型
+#[repr(i16)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, IntEnum)]
+pub enum FlockType {
+    F_RDLCK = 0,
+    F_WRLCK = 1,
+    F_UNLCK = 2,
+}
+
+/// `F_GETLK`/`F_SETLK`/`F_SETLKW` 以及 OFD 锁使用的 `struct flock`
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Pod)]
+pub struct Flock {
+    /// 锁的类型，见 [`FlockType`]
+    pub l_type: i16,
+    /// `l_start` 的起点，见 [`Whence`]
+    pub l_whence: i16,
+    pub __pad: u32,
+    pub l_start: i64,
+    /// 为 0 时表示一直锁到文件末尾之后，为负数时表示 `l_start` 之前的区域
+    pub l_len: i64,
+    /// 持有冲突锁的进程，OFD 锁为 -1
+    pub l_pid: i32,
+    pub __pad2: u32,
+}
+
+impl Flock {
+    /// 锁的类型，不合法时返回 `EINVAL`
+    pub fn lock_type(&self) -> Result<FlockType, LinuxErrno> {
+        FlockType::try_from(self.l_type).map_err(|_| LinuxErrno::EINVAL)
+    }
+}
+
+/// [`FOwnerEx`] 中 owner 的类型
+#[repr(i32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, IntEnum)]
+pub enum FOwnerType {
+    F_OWNER_TID = 0,
+    F_OWNER_PID = 1,
+    F_OWNER_PGRP = 2,
+}
+
+/// `F_GETOWN_EX`/`F_SETOWN_EX` 使用的 `struct f_owner_ex`
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Pod)]
+pub struct FOwnerEx {
+    /// owner 的类型，见 [`FOwnerType`]
+    pub ty: i32,
+    pub pid: i32,
+}
+
+bitflags! {
+    /// memfd 的封印，`F_ADD_SEALS`/`F_GET_SEALS` 使用
+    pub struct SealFlags: u32 {
+        /// 禁止再添加封印
+        const F_SEAL_SEAL = 0x0001;
+        /// 禁止缩小文件
+        const F_SEAL_SHRINK = 0x0002;
+        /// 禁止扩大文件
+        const F_SEAL_GROW = 0x0004;
+        /// 禁止写入
+        const F_SEAL_WRITE = 0x0008;
+        /// 禁止之后的写入，已有的共享可写映射不受影响
+        const F_SEAL_FUTURE_WRITE = 0x0010;
+        /// 禁止修改执行权限
+        const F_SEAL_EXEC = 0x0020;
+    }
+}
+
+/// 按照 fcntl 的命令解析出的参数
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FcntlArg {
+    /// 命令不使用参数
+    None,
+    /// `F_DUPFD`/`F_DUPFD_CLOEXEC`：新 fd 的最小值；`F_DUPFD_QUERY`：要比较的 fd
+    Fd(usize),
+    /// `F_SETFD`：是否设置 [`FD_CLOEXEC`]
+    CloseOnExec(bool),
+    /// `F_SETFL`：新的文件状态标志
+    StatusFlags(OpenFlags),
+    /// 记录锁命令：指向 [`Flock`] 的用户指针
+    Flock(usize),
+    /// `F_SETOWN`：正数为进程，负数为进程组
+    Owner(i32),
+    /// `F_GETOWN_EX`/`F_SETOWN_EX`：指向 [`FOwnerEx`] 的用户指针
+    OwnerEx(usize),
+    /// `F_GETOWNER_UIDS`：指向两个 `u32` 的用户指针
+    OwnerUids(usize),
+    /// `F_SETSIG`：`None` 表示使用 SIGIO
+    Signal(Option<SignalNumber>),
+    /// `F_SETLEASE`
+    Lease(FlockType),
+    /// `F_NOTIFY`：`DN_*` 标志
+    Notify(u32),
+    /// `F_SETPIPE_SZ`：新的管道容量
+    PipeSize(usize),
+    /// `F_ADD_SEALS`
+    Seals(SealFlags),
+    /// 读写提示命令：指向 `u64` 的用户指针
+    RwHint(usize),
+}
+
+impl FcntlArg {
+    /// 按照 `cmd` 解析 fcntl 的 `arg` 参数，参数不合法时返回 `EINVAL`。
+    ///
+    /// 与 Linux 一致，`F_SETFL` 中无法识别的位会被忽略。
+    pub fn decode(cmd: Fcntl64Cmd, arg: usize) -> Result<Self, LinuxErrno> {
+        let arg = match cmd {
+            Fcntl64Cmd::F_DUPFD | Fcntl64Cmd::F_DUPFD_CLOEXEC | Fcntl64Cmd::DUPFD_QUERY => {
+                FcntlArg::Fd(arg)
+            }
+            Fcntl64Cmd::F_SETFD => FcntlArg::CloseOnExec(arg & FD_CLOEXEC != 0),
+            Fcntl64Cmd::F_SETFL => FcntlArg::StatusFlags(OpenFlags::from_bits_truncate(arg)),
+            Fcntl64Cmd::GETLK
+            | Fcntl64Cmd::SETLK
+            | Fcntl64Cmd::SETLKW
+            | Fcntl64Cmd::OFD_GETLK
+            | Fcntl64Cmd::OFD_SETLK
+            | Fcntl64Cmd::OFD_SETLKW => FcntlArg::Flock(arg),
+            Fcntl64Cmd::SETOWN => FcntlArg::Owner(arg as i32),
+            Fcntl64Cmd::SETOWN_EX | Fcntl64Cmd::GETOWN_EX => FcntlArg::OwnerEx(arg),
+            Fcntl64Cmd::GETOWNER_UIDS => FcntlArg::OwnerUids(arg),
+            Fcntl64Cmd::SETSIG if arg == 0 => FcntlArg::Signal(None),
+            Fcntl64Cmd::SETSIG => u8::try_from(arg)
+                .ok()
+                .and_then(|sig| SignalNumber::try_from(sig).ok())
+                .map(|sig| FcntlArg::Signal(Some(sig)))
+                .ok_or(LinuxErrno::EINVAL)?,
+            Fcntl64Cmd::SETLEASE => i16::try_from(arg)
+                .ok()
+                .and_then(|ty| FlockType::try_from(ty).ok())
+                .map(FcntlArg::Lease)
+                .ok_or(LinuxErrno::EINVAL)?,
+            Fcntl64Cmd::NOTIFY => FcntlArg::Notify(arg as u32),
+            Fcntl64Cmd::SETPIPE_SZ => FcntlArg::PipeSize(arg),
+            Fcntl64Cmd::ADD_SEALS => u32::try_from(arg)
+                .ok()
+                .and_then(SealFlags::from_bits)
+                .map(FcntlArg::Seals)
+                .ok_or(LinuxErrno::EINVAL)?,
+            Fcntl64Cmd::GET_RW_HINT
+            | Fcntl64Cmd::SET_RW_HINT
+            | Fcntl64Cmd::GET_FILE_RW_HINT
+            | Fcntl64Cmd::SET_FILE_RW_HINT => FcntlArg::RwHint(arg),
+            Fcntl64Cmd::F_GETFD
+            | Fcntl64Cmd::F_GETFL
+            | Fcntl64Cmd::GETOWN
+            | Fcntl64Cmd::GETSIG
+            | Fcntl64Cmd::GETLEASE
+            | Fcntl64Cmd::CREATED_QUERY
+            | Fcntl64Cmd::CANCELLK
+            | Fcntl64Cmd::GETPIPE_SZ
+            | Fcntl64Cmd::GET_SEALS => FcntlArg::None,
+        };
+        Ok(arg)
+    }
 }
 
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Default)]
 pub struct FsStat {
     /// 是个 magic number，每个知名的 fs 都各有定义，但显然我们没有
     pub f_type: i64,
@@ -92,25 +328,28 @@ pub struct FsStat {
     pub f_spare: [isize; 4],
 }
 
-impl Default for FsStat {
-    fn default() -> Self {
-        Self {
-            f_type: 0,
-            f_bsize: 0,
-            f_blocks: 0,
-            f_bfree: 0,
-            f_bavail: 0,
-            f_files: 0,
-            f_ffree: 0,
-            f_fsid: [0, 0],
-            f_namelen: 0,
-            f_frsize: 0,
-            f_flags: 0,
-            f_spare: [0; 4],
-        }
-    }
+/// 与体系结构无关的文件信息，可以转换为各体系结构的 [`FileStat`] 以及 [`Statx`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatInfo {
+    pub dev: u64,
+    pub ino: u64,
+    /// 文件类型与权限，见 [`InodeMode`]
+    pub mode: u32,
+    pub nlink: u32,
+    pub uid: u32,
+    pub gid: u32,
+    pub rdev: u64,
+    pub size: u64,
+    pub blksize: u32,
+    /// 以 512 字节为单位的块数
+    pub blocks: u64,
+    pub atime: TimeSpec,
+    pub mtime: TimeSpec,
+    pub ctime: TimeSpec,
 }
 
+/// asm-generic 的 `struct stat`，riscv64、aarch64 与 loongarch64 使用此布局
+#[cfg(not(feature = "arch-x86_64"))]
 #[derive(Debug, Clone, Copy, Default, Pod)]
 #[repr(C)]
 pub struct FileStat {
@@ -135,6 +374,325 @@ pub struct FileStat {
     pub unused: u64,
 } //128
 
+/// x86_64 的 `struct stat`，`st_nlink` 为 64 位且位于 `st_mode` 之前
+#[cfg(feature = "arch-x86_64")]
+#[derive(Debug, Clone, Copy, Default, Pod)]
+#[repr(C)]
+pub struct FileStat {
+    pub st_dev: u64,
+    pub st_ino: u64,
+    pub st_nlink: u64,
+    pub st_mode: u32,
+    pub st_uid: u32,
+    pub st_gid: u32,
+    pub __pad0: u32,
+    pub st_rdev: u64,
+    pub st_size: u64,
+    pub st_blksize: u64,
+    pub st_blocks: u64,
+    pub st_atime_sec: u64,
+    pub st_atime_nsec: u64,
+    pub st_mtime_sec: u64,
+    pub st_mtime_nsec: u64,
+    pub st_ctime_sec: u64,
+    pub st_ctime_nsec: u64,
+    pub __unused: [u64; 3],
+} //144
+
+#[cfg(not(feature = "arch-x86_64"))]
+impl From<StatInfo> for FileStat {
+    fn from(info: StatInfo) -> Self {
+        Self {
+            st_dev: info.dev,
+            st_ino: info.ino,
+            st_mode: info.mode,
+            st_nlink: info.nlink,
+            st_uid: info.uid,
+            st_gid: info.gid,
+            st_rdev: info.rdev,
+            st_size: info.size,
+            st_blksize: info.blksize,
+            st_blocks: info.blocks,
+            st_atime_sec: info.atime.tv_sec as u64,
+            st_atime_nsec: info.atime.tv_nsec as u64,
+            st_mtime_sec: info.mtime.tv_sec as u64,
+            st_mtime_nsec: info.mtime.tv_nsec as u64,
+            st_ctime_sec: info.ctime.tv_sec as u64,
+            st_ctime_nsec: info.ctime.tv_nsec as u64,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "arch-x86_64")]
+impl From<StatInfo> for FileStat {
+    fn from(info: StatInfo) -> Self {
+        Self {
+            st_dev: info.dev,
+            st_ino: info.ino,
+            st_nlink: info.nlink.into(),
+            st_mode: info.mode,
+            st_uid: info.uid,
+            st_gid: info.gid,
+            st_rdev: info.rdev,
+            st_size: info.size,
+            st_blksize: info.blksize.into(),
+            st_blocks: info.blocks,
+            st_atime_sec: info.atime.tv_sec as u64,
+            st_atime_nsec: info.atime.tv_nsec as u64,
+            st_mtime_sec: info.mtime.tv_sec as u64,
+            st_mtime_nsec: info.mtime.tv_nsec as u64,
+            st_ctime_sec: info.ctime.tv_sec as u64,
+            st_ctime_nsec: info.ctime.tv_nsec as u64,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<FileStat> for StatInfo {
+    fn from(stat: FileStat) -> Self {
+        Self {
+            dev: stat.st_dev,
+            ino: stat.st_ino,
+            mode: stat.st_mode,
+            #[allow(clippy::unnecessary_cast)]
+            nlink: stat.st_nlink as u32,
+            uid: stat.st_uid,
+            gid: stat.st_gid,
+            rdev: stat.st_rdev,
+            size: stat.st_size,
+            #[allow(clippy::unnecessary_cast)]
+            blksize: stat.st_blksize as u32,
+            blocks: stat.st_blocks,
+            atime: TimeSpec::new(stat.st_atime_sec as usize, stat.st_atime_nsec as usize),
+            mtime: TimeSpec::new(stat.st_mtime_sec as usize, stat.st_mtime_nsec as usize),
+            ctime: TimeSpec::new(stat.st_ctime_sec as usize, stat.st_ctime_nsec as usize),
+        }
+    }
+}
+
+// 与内核头文件 (asm-generic/stat.h, arch/x86/include/uapi/asm/stat.h) 中的布局对照
+#[cfg(not(feature = "arch-x86_64"))]
+const _: () = {
+    assert!(core::mem::size_of::<FileStat>() == 128);
+    assert!(offset_of!(FileStat, st_mode) == 16);
+    assert!(offset_of!(FileStat, st_nlink) == 20);
+    assert!(offset_of!(FileStat, st_rdev) == 32);
+    assert!(offset_of!(FileStat, st_size) == 48);
+    assert!(offset_of!(FileStat, st_blksize) == 56);
+    assert!(offset_of!(FileStat, st_blocks) == 64);
+    assert!(offset_of!(FileStat, st_atime_sec) == 72);
+    assert!(offset_of!(FileStat, st_ctime_nsec) == 112);
+};
+
+#[cfg(feature = "arch-x86_64")]
+const _: () = {
+    assert!(core::mem::size_of::<FileStat>() == 144);
+    assert!(offset_of!(FileStat, st_nlink) == 16);
+    assert!(offset_of!(FileStat, st_mode) == 24);
+    assert!(offset_of!(FileStat, st_rdev) == 40);
+    assert!(offset_of!(FileStat, st_size) == 48);
+    assert!(offset_of!(FileStat, st_blksize) == 56);
+    assert!(offset_of!(FileStat, st_blocks) == 64);
+    assert!(offset_of!(FileStat, st_atime_sec) == 72);
+    assert!(offset_of!(FileStat, st_ctime_nsec) == 112);
+};
+
+// 与 include/uapi/linux/stat.h 中的 `struct statx` 对照，各体系结构相同
+const _: () = {
+    assert!(core::mem::size_of::<StatxTimestamp>() == 16);
+    assert!(core::mem::size_of::<Statx>() == 256);
+    assert!(offset_of!(Statx, stx_attributes) == 8);
+    assert!(offset_of!(Statx, stx_nlink) == 16);
+    assert!(offset_of!(Statx, stx_mode) == 28);
+    assert!(offset_of!(Statx, stx_ino) == 32);
+    assert!(offset_of!(Statx, stx_attributes_mask) == 56);
+    assert!(offset_of!(Statx, stx_atime) == 64);
+    assert!(offset_of!(Statx, stx_btime) == 80);
+    assert!(offset_of!(Statx, stx_ctime) == 96);
+    assert!(offset_of!(Statx, stx_mtime) == 112);
+    assert!(offset_of!(Statx, stx_rdev_major) == 128);
+    assert!(offset_of!(Statx, stx_dev_major) == 136);
+    assert!(offset_of!(Statx, stx_mnt_id) == 144);
+    assert!(offset_of!(Statx, stx_dio_mem_align) == 152);
+    assert!(offset_of!(Statx, __spare3) == 160);
+};
+
+/// `statx` 中的时间戳
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Pod)]
+pub struct StatxTimestamp {
+    pub tv_sec: i64,
+    pub tv_nsec: u32,
+    pub __reserved: i32,
+}
+
+impl StatxTimestamp {
+    pub fn new(sec: i64, nsec: u32) -> Self {
+        Self {
+            tv_sec: sec,
+            tv_nsec: nsec,
+            __reserved: 0,
+        }
+    }
+}
+
+impl From<TimeSpec> for StatxTimestamp {
+    fn from(time: TimeSpec) -> Self {
+        Self::new(time.tv_sec as i64, time.tv_nsec as u32)
+    }
+}
+
+/// [`Statx::stx_mode`] 的类型，包含文件类型与权限位
+pub type StatxMode = u16;
+
+/// `statx` 系统调用返回的文件信息
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, Pod)]
+pub struct Statx {
+    /// 实际填写了哪些字段，见 [`StatxMask`]
+    pub stx_mask: u32,
+    pub stx_blksize: u32,
+    /// 文件属性，见 [`StatxAttributes`]
+    pub stx_attributes: u64,
+    pub stx_nlink: u32,
+    pub stx_uid: u32,
+    pub stx_gid: u32,
+    pub stx_mode: StatxMode,
+    pub __spare0: u16,
+    pub stx_ino: u64,
+    pub stx_size: u64,
+    pub stx_blocks: u64,
+    /// 文件系统支持的属性
+    pub stx_attributes_mask: u64,
+    pub stx_atime: StatxTimestamp,
+    pub stx_btime: StatxTimestamp,
+    pub stx_ctime: StatxTimestamp,
+    pub stx_mtime: StatxTimestamp,
+    pub stx_rdev_major: u32,
+    pub stx_rdev_minor: u32,
+    pub stx_dev_major: u32,
+    pub stx_dev_minor: u32,
+    pub stx_mnt_id: u64,
+    pub stx_dio_mem_align: u32,
+    pub stx_dio_offset_align: u32,
+    pub __spare3: [u64; 12],
+} //256
+
+impl Statx {
+    /// 设置文件类型与权限，并在 `stx_mask` 中标记 `STATX_TYPE | STATX_MODE`
+    pub fn set_mode(&mut self, mode: InodeMode) {
+        self.stx_mode = mode.bits() as StatxMode;
+        self.stx_mask |= (StatxMask::STATX_TYPE | StatxMask::STATX_MODE).bits();
+    }
+
+    /// 只保留调用者请求的字段对应的标记。
+    ///
+    /// Linux 允许返回比请求更多的字段，但文件类型等基本信息总是有效的，
+    /// 这里只去掉调用者既没有请求、也不属于 `STATX_BASIC_STATS` 的标记。
+    pub fn restrict_mask(&mut self, requested: StatxMask) {
+        self.stx_mask &= (requested | StatxMask::STATX_BASIC_STATS).bits();
+    }
+}
+
+impl From<StatInfo> for Statx {
+    fn from(info: StatInfo) -> Self {
+        Self {
+            stx_mask: StatxMask::STATX_BASIC_STATS.bits(),
+            stx_blksize: info.blksize,
+            stx_nlink: info.nlink,
+            stx_uid: info.uid,
+            stx_gid: info.gid,
+            stx_mode: info.mode as StatxMode,
+            stx_ino: info.ino,
+            stx_size: info.size,
+            stx_blocks: info.blocks,
+            stx_atime: info.atime.into(),
+            stx_ctime: info.ctime.into(),
+            stx_mtime: info.mtime.into(),
+            stx_rdev_major: dev_major(info.rdev),
+            stx_rdev_minor: dev_minor(info.rdev),
+            stx_dev_major: dev_major(info.dev),
+            stx_dev_minor: dev_minor(info.dev),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<FileStat> for Statx {
+    fn from(stat: FileStat) -> Self {
+        StatInfo::from(stat).into()
+    }
+}
+
+/// 从 `dev_t` 中取出主设备号，编码方式与 glibc 的 `major` 相同
+pub const fn dev_major(dev: u64) -> u32 {
+    (((dev >> 32) & 0xffff_f000) | ((dev >> 8) & 0x0000_0fff)) as u32
+}
+
+/// 从 `dev_t` 中取出次设备号，编码方式与 glibc 的 `minor` 相同
+pub const fn dev_minor(dev: u64) -> u32 {
+    (((dev >> 12) & 0xffff_ff00) | (dev & 0x0000_00ff)) as u32
+}
+
+/// 由主次设备号构造 `dev_t`，编码方式与 glibc 的 `makedev` 相同
+pub const fn makedev(major: u32, minor: u32) -> u64 {
+    let (major, minor) = (major as u64, minor as u64);
+    ((major & 0xffff_f000) << 32)
+        | ((major & 0x0000_0fff) << 8)
+        | ((minor & 0xffff_ff00) << 12)
+        | (minor & 0x0000_00ff)
+}
+
+bitflags! {
+    /// `statx` 的 `mask` 参数以及 [`Statx::stx_mask`]
+    pub struct StatxMask: u32 {
+        /// stx_mode & S_IFMT
+        const STATX_TYPE = 0x0001;
+        /// stx_mode & ~S_IFMT
+        const STATX_MODE = 0x0002;
+        const STATX_NLINK = 0x0004;
+        const STATX_UID = 0x0008;
+        const STATX_GID = 0x0010;
+        const STATX_ATIME = 0x0020;
+        const STATX_MTIME = 0x0040;
+        const STATX_CTIME = 0x0080;
+        const STATX_INO = 0x0100;
+        const STATX_SIZE = 0x0200;
+        const STATX_BLOCKS = 0x0400;
+        /// 普通 `stat` 中包含的所有信息
+        const STATX_BASIC_STATS = 0x07ff;
+        const STATX_BTIME = 0x0800;
+        const STATX_MNT_ID = 0x1000;
+        /// stx_dio_mem_align 与 stx_dio_offset_align
+        const STATX_DIOALIGN = 0x2000;
+    }
+}
+
+bitflags! {
+    /// [`Statx::stx_attributes`] 与 [`Statx::stx_attributes_mask`]
+    pub struct StatxAttributes: u64 {
+        /// File is compressed by the fs
+        const STATX_ATTR_COMPRESSED = 0x0000_0004;
+        /// File is marked immutable
+        const STATX_ATTR_IMMUTABLE = 0x0000_0010;
+        /// File is append-only
+        const STATX_ATTR_APPEND = 0x0000_0020;
+        /// File is not to be dumped
+        const STATX_ATTR_NODUMP = 0x0000_0040;
+        /// File requires key to decrypt in fs
+        const STATX_ATTR_ENCRYPTED = 0x0000_0800;
+        /// Dir: Automount trigger
+        const STATX_ATTR_AUTOMOUNT = 0x0000_1000;
+        /// Root of a mount
+        const STATX_ATTR_MOUNT_ROOT = 0x0000_2000;
+        /// Verity protected file
+        const STATX_ATTR_VERITY = 0x0010_0000;
+        /// File is currently in DAX state
+        const STATX_ATTR_DAX = 0x0020_0000;
+    }
+}
+
 pub type PollEvents = EpollEventType;
 
 #[repr(C)]
@@ -187,8 +745,9 @@ bitflags! {
     }
 }
 
+/// 终端窗口的大小，用于 `TIOCGWINSZ` 与 `TIOCSWINSZ`
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Pod)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Pod)]
 pub struct WinSize {
     ws_row: u16,
     ws_col: u16,
@@ -196,14 +755,53 @@ pub struct WinSize {
     ypixel: u16,
 }
 
-impl Default for WinSize {
-    fn default() -> Self {
+impl WinSize {
+    /// `rows` 行 `cols` 列，像素大小未知
+    pub const fn new(rows: u16, cols: u16) -> Self {
+        Self::with_pixels(rows, cols, 0, 0)
+    }
+
+    /// 同时给出以像素为单位的宽度与高度
+    pub const fn with_pixels(rows: u16, cols: u16, xpixel: u16, ypixel: u16) -> Self {
         Self {
-            ws_row: 24,
-            ws_col: 80,
-            xpixel: 0,
-            ypixel: 0,
+            ws_row: rows,
+            ws_col: cols,
+            xpixel,
+            ypixel,
+        }
+    }
+
+    pub const fn rows(&self) -> u16 {
+        self.ws_row
+    }
+
+    pub const fn cols(&self) -> u16 {
+        self.ws_col
+    }
+
+    /// 宽度，单位为像素，0 表示未知
+    pub const fn xpixel(&self) -> u16 {
+        self.xpixel
+    }
+
+    /// 高度，单位为像素，0 表示未知
+    pub const fn ypixel(&self) -> u16 {
+        self.ypixel
+    }
+
+    /// 更新窗口大小，大小确实改变时返回需要发送给前台进程组的 `SIGWINCH`
+    pub fn update(&mut self, new: WinSize) -> Option<SignalNumber> {
+        if *self == new {
+            return None;
         }
+        *self = new;
+        Some(SignalNumber::SIGWINCH)
+    }
+}
+
+impl Default for WinSize {
+    fn default() -> Self {
+        Self::new(24, 80)
     }
 }
 
@@ -229,14 +827,38 @@ pub enum TeletypeCommand {
     TCSETAW = 0x5407,
     /// Sets the serial port settings after flushing the input and output buffers.
     TCSETAF = 0x5408,
+    /// Sends a break, or waits for the output to drain when the argument is non-zero.
+    TCSBRK = 0x5409,
+    /// Suspends or restarts transmission or reception, see [`FlowAction`].
+    TCXONC = 0x540A,
+    /// Discards queued input and/or output, see [`FlushQueue`].
+    TCFLSH = 0x540B,
+    /// Make the given terminal the controlling terminal of the calling process.
+    TIOCSCTTY = 0x540E,
     /// Get the process group ID of the foreground process group on this terminal.
     TIOCGPGRP = 0x540F,
     /// Set the foreground process group ID of this terminal.
     TIOCSPGRP = 0x5410,
+    /// Get the number of bytes in the output buffer.
+    TIOCOUTQ = 0x5411,
+    /// Insert the given byte in the input queue.
+    TIOCSTI = 0x5412,
     /// Get window size.
     TIOCGWINSZ = 0x5413,
     /// Set window size.
     TIOCSWINSZ = 0x5414,
+    /// Get the number of bytes in the input buffer, also known as `TIOCINQ`.
+    FIONREAD = 0x541B,
+    /// Give up the controlling terminal.
+    TIOCNOTTY = 0x5422,
+    /// Get the session ID of the session this terminal controls.
+    TIOCGSID = 0x5429,
+    /// Get the index of the pty slave, `_IOR('T', 0x30, u32)`.
+    TIOCGPTN = 0x80045430,
+    /// Lock or unlock the pty slave, `_IOW('T', 0x31, i32)`.
+    TIOCSPTLCK = 0x40045431,
+    /// Open the pty slave and return a new fd, the argument is the open flags.
+    TIOCGPTPEER = 0x5441,
     /// Non-cloexec
     FIONCLEX = 0x5450,
     /// Cloexec
@@ -244,9 +866,34 @@ pub enum TeletypeCommand {
     /// rustc using pipe and ioctl pipe file with this request id
     /// for non-blocking/blocking IO control setting
     FIONBIO = 0x5421,
-    /// Read time
+    /// Read time, same as [`RtcCommand::RTC_RD_TIME`](crate::ioctl::RtcCommand::RTC_RD_TIME).
     RTC_RD_TIME = 0x80247009,
-    ILLEAGAL = 0,
+}
+
+/// `TCFLSH` 的参数
+#[repr(usize)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, IntEnum)]
+pub enum FlushQueue {
+    /// 丢弃收到但未读取的数据
+    TCIFLUSH = 0,
+    /// 丢弃写入但未发送的数据
+    TCOFLUSH = 1,
+    /// 两者都丢弃
+    TCIOFLUSH = 2,
+}
+
+/// `TCXONC` 的参数
+#[repr(usize)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, IntEnum)]
+pub enum FlowAction {
+    /// 暂停输出
+    TCOOFF = 0,
+    /// 恢复输出
+    TCOON = 1,
+    /// 发送 STOP 字符，要求对端停止发送
+    TCIOFF = 2,
+    /// 发送 START 字符，要求对端恢复发送
+    TCION = 3,
 }
 
 #[repr(C)]
@@ -271,42 +918,330 @@ pub struct Termios {
 
 impl Default for Termios {
     fn default() -> Self {
+        let mut cc = [0; 32];
+        cc[ControlChar::VINTR as usize] = 0x03; // Ctrl-C
+        cc[ControlChar::VQUIT as usize] = 0x1c; // Ctrl-\
+        cc[ControlChar::VERASE as usize] = 0x7f; // DEL
+        cc[ControlChar::VKILL as usize] = 0x15; // Ctrl-U
+        cc[ControlChar::VEOF as usize] = 0x04; // Ctrl-D
+        cc[ControlChar::VTIME as usize] = 0;
+        cc[ControlChar::VMIN as usize] = 1;
+        cc[ControlChar::VSTART as usize] = 0x11; // Ctrl-Q
+        cc[ControlChar::VSTOP as usize] = 0x13; // Ctrl-S
+        cc[ControlChar::VSUSP as usize] = 0x1a; // Ctrl-Z
+        cc[ControlChar::VEOL as usize] = 0xff;
+        cc[ControlChar::VREPRINT as usize] = 0x12; // Ctrl-R
+        cc[ControlChar::VDISCARD as usize] = 0x0f; // Ctrl-O
+        cc[ControlChar::VWERASE as usize] = 0x17; // Ctrl-W
+        cc[ControlChar::VLNEXT as usize] = 0x16; // Ctrl-V
+        cc[ControlChar::VEOL2 as usize] = 0xff;
         Termios {
-            // IMAXBEL | IUTF8 | IXON | IXANY | ICRNL | BRKINT
-            iflag: 0o66402,
-            // OPOST | ONLCR
-            oflag: 0o5,
-            // HUPCL | CREAD | CSIZE | EXTB
-            cflag: 0o2277,
-            // IEXTEN | ECHOTCL | ECHOKE ECHO | ECHOE | ECHOK | ISIG | ICANON
-            lflag: 0o105073,
+            iflag: (InputModes::IMAXBEL
+                | InputModes::IUTF8
+                | InputModes::IXON
+                | InputModes::IXANY
+                | InputModes::ICRNL
+                | InputModes::BRKINT)
+                .bits(),
+            oflag: (OutputModes::OPOST | OutputModes::ONLCR).bits(),
+            cflag: (ControlModes::HUPCL | ControlModes::CREAD | ControlModes::CS8).bits()
+                | BaudRate::B38400 as u32,
+            lflag: (LocalModes::IEXTEN
+                | LocalModes::ECHOCTL
+                | LocalModes::ECHOKE
+                | LocalModes::ECHO
+                | LocalModes::ECHOE
+                | LocalModes::ECHOK
+                | LocalModes::ISIG
+                | LocalModes::ICANON)
+                .bits(),
             line: 0,
-            cc: [
-                3,   // VINTR Ctrl-C
-                28,  // VQUIT
-                127, // VERASE
-                21,  // VKILL
-                4,   // VEOF Ctrl-D
-                0,   // VTIME
-                1,   // VMIN
-                0,   // VSWTC
-                17,  // VSTART
-                19,  // VSTOP
-                26,  // VSUSP Ctrl-Z
-                255, // VEOL
-                18,  // VREPAINT
-                15,  // VDISCARD
-                23,  // VWERASE
-                22,  // VLNEXT
-                255, // VEOL2
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
+            cc,
             ispeed: 0,
             ospeed: 0,
         }
     }
 }
 
+impl Termios {
+    pub fn input_modes(&self) -> InputModes {
+        InputModes::from_bits_truncate(self.iflag)
+    }
+
+    pub fn set_input_modes(&mut self, modes: InputModes) {
+        self.iflag = modes.bits();
+    }
+
+    pub fn output_modes(&self) -> OutputModes {
+        OutputModes::from_bits_truncate(self.oflag)
+    }
+
+    pub fn set_output_modes(&mut self, modes: OutputModes) {
+        self.oflag = modes.bits();
+    }
+
+    /// 控制模式，不含波特率，波特率见 [`Termios::cfgetospeed`]
+    pub fn control_modes(&self) -> ControlModes {
+        ControlModes::from_bits_truncate(self.cflag)
+    }
+
+    /// 设置控制模式，波特率保持不变
+    pub fn set_control_modes(&mut self, modes: ControlModes) {
+        self.cflag = (self.cflag & (CBAUD | CIBAUD)) | modes.bits();
+    }
+
+    pub fn local_modes(&self) -> LocalModes {
+        LocalModes::from_bits_truncate(self.lflag)
+    }
+
+    pub fn set_local_modes(&mut self, modes: LocalModes) {
+        self.lflag = modes.bits();
+    }
+
+    /// 读取控制字符
+    pub fn cc(&self, index: ControlChar) -> u8 {
+        self.cc[index as usize]
+    }
+
+    /// 设置控制字符
+    pub fn set_cc(&mut self, index: ControlChar, value: u8) {
+        self.cc[index as usize] = value;
+    }
+
+    /// 输出波特率
+    pub fn cfgetospeed(&self) -> BaudRate {
+        BaudRate::try_from(self.cflag & CBAUD).unwrap_or(BaudRate::B0)
+    }
+
+    /// 输入波特率，未单独设置时与输出波特率相同
+    pub fn cfgetispeed(&self) -> BaudRate {
+        match BaudRate::try_from((self.cflag & CIBAUD) >> IBSHIFT) {
+            Ok(BaudRate::B0) | Err(_) => self.cfgetospeed(),
+            Ok(speed) => speed,
+        }
+    }
+
+    pub fn cfsetospeed(&mut self, speed: BaudRate) {
+        self.cflag = (self.cflag & !CBAUD) | speed as u32;
+        self.ospeed = speed as u32;
+    }
+
+    /// 设置输入波特率，`B0` 表示与输出波特率相同
+    pub fn cfsetispeed(&mut self, speed: BaudRate) {
+        self.cflag = (self.cflag & !CIBAUD) | ((speed as u32) << IBSHIFT);
+        self.ispeed = speed as u32;
+    }
+
+    /// 与 glibc 的 `cfmakeraw` 相同：关闭回显、行缓冲、信号与输入输出的转换
+    pub fn cfmakeraw(&mut self) {
+        self.set_input_modes(
+            self.input_modes()
+                - (InputModes::IGNBRK
+                    | InputModes::BRKINT
+                    | InputModes::PARMRK
+                    | InputModes::ISTRIP
+                    | InputModes::INLCR
+                    | InputModes::IGNCR
+                    | InputModes::ICRNL
+                    | InputModes::IXON),
+        );
+        self.set_output_modes(self.output_modes() - OutputModes::OPOST);
+        self.set_local_modes(
+            self.local_modes()
+                - (LocalModes::ECHO
+                    | LocalModes::ECHONL
+                    | LocalModes::ICANON
+                    | LocalModes::ISIG
+                    | LocalModes::IEXTEN),
+        );
+        self.set_control_modes(
+            (self.control_modes() - (ControlModes::CSIZE | ControlModes::PARENB))
+                | ControlModes::CS8,
+        );
+        self.set_cc(ControlChar::VMIN, 1);
+        self.set_cc(ControlChar::VTIME, 0);
+    }
+}
+
+/// [`Termios::cc`] 中各个控制字符的下标
+#[repr(usize)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, IntEnum)]
+pub enum ControlChar {
+    /// 发送 SIGINT
+    VINTR = 0,
+    /// 发送 SIGQUIT
+    VQUIT = 1,
+    /// 删除前一个字符
+    VERASE = 2,
+    /// 删除整行
+    VKILL = 3,
+    /// 文件结束
+    VEOF = 4,
+    /// 非规范模式下读取的超时，单位为 0.1 秒
+    VTIME = 5,
+    /// 非规范模式下读取的最少字节数
+    VMIN = 6,
+    VSWTC = 7,
+    /// 恢复输出
+    VSTART = 8,
+    /// 暂停输出
+    VSTOP = 9,
+    /// 发送 SIGTSTP
+    VSUSP = 10,
+    /// 额外的行结束符
+    VEOL = 11,
+    /// 重新显示未读的输入
+    VREPRINT = 12,
+    VDISCARD = 13,
+    /// 删除前一个单词
+    VWERASE = 14,
+    /// 将下一个字符作为普通字符输入
+    VLNEXT = 15,
+    /// 额外的行结束符
+    VEOL2 = 16,
+}
+
+bitflags! {
+    pub struct InputModes: u32 {
+        const IGNBRK = 0o000001;
+        const BRKINT = 0o000002;
+        const IGNPAR = 0o000004;
+        const PARMRK = 0o000010;
+        const INPCK = 0o000020;
+        const ISTRIP = 0o000040;
+        const INLCR = 0o000100;
+        const IGNCR = 0o000200;
+        const ICRNL = 0o000400;
+        const IUCLC = 0o001000;
+        const IXON = 0o002000;
+        const IXANY = 0o004000;
+        const IXOFF = 0o010000;
+        const IMAXBEL = 0o020000;
+        const IUTF8 = 0o040000;
+    }
+}
+
+bitflags! {
+    pub struct OutputModes: u32 {
+        const OPOST = 0o000001;
+        const OLCUC = 0o000002;
+        const ONLCR = 0o000004;
+        const OCRNL = 0o000010;
+        const ONOCR = 0o000020;
+        const ONLRET = 0o000040;
+        const OFILL = 0o000100;
+        const OFDEL = 0o000200;
+        const NLDLY = 0o000400;
+        const CRDLY = 0o003000;
+        const TABDLY = 0o014000;
+        const BSDLY = 0o020000;
+        const VTDLY = 0o040000;
+        const FFDLY = 0o100000;
+    }
+}
+
+bitflags! {
+    /// 控制模式，波特率 ([`CBAUD`]/[`CIBAUD`]) 不在其中
+    pub struct ControlModes: u32 {
+        const CSIZE = 0o000060;
+        const CS5 = 0o000000;
+        const CS6 = 0o000020;
+        const CS7 = 0o000040;
+        const CS8 = 0o000060;
+        const CSTOPB = 0o000100;
+        const CREAD = 0o000200;
+        const PARENB = 0o000400;
+        const PARODD = 0o001000;
+        const HUPCL = 0o002000;
+        const CLOCAL = 0o004000;
+        const CMSPAR = 0o10000000000;
+        const CRTSCTS = 0o20000000000;
+    }
+}
+
+/// cflag 中输出波特率所在的位
+pub const CBAUD: u32 = 0o010017;
+/// cflag 中输入波特率所在的位
+pub const CIBAUD: u32 = CBAUD << IBSHIFT;
+/// 输入波特率在 cflag 中的偏移
+pub const IBSHIFT: u32 = 16;
+
+/// 波特率，值为 cflag 中 [`CBAUD`] 部分的编码
+#[repr(u32)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, IntEnum)]
+pub enum BaudRate {
+    B0 = 0o0,
+    B50 = 0o1,
+    B75 = 0o2,
+    B110 = 0o3,
+    B134 = 0o4,
+    B150 = 0o5,
+    B200 = 0o6,
+    B300 = 0o7,
+    B600 = 0o10,
+    B1200 = 0o11,
+    B1800 = 0o12,
+    B2400 = 0o13,
+    B4800 = 0o14,
+    B9600 = 0o15,
+    B19200 = 0o16,
+    B38400 = 0o17,
+    B57600 = 0o10001,
+    B115200 = 0o10002,
+    B230400 = 0o10003,
+    B460800 = 0o10004,
+    B500000 = 0o10005,
+    B576000 = 0o10006,
+    B921600 = 0o10007,
+    B1000000 = 0o10010,
+    B1152000 = 0o10011,
+    B1500000 = 0o10012,
+    B2000000 = 0o10013,
+    B2500000 = 0o10014,
+    B3000000 = 0o10015,
+    B3500000 = 0o10016,
+    B4000000 = 0o10017,
+}
+
+impl BaudRate {
+    /// 每秒的位数
+    pub const fn bits_per_second(self) -> u32 {
+        match self {
+            BaudRate::B0 => 0,
+            BaudRate::B50 => 50,
+            BaudRate::B75 => 75,
+            BaudRate::B110 => 110,
+            BaudRate::B134 => 134,
+            BaudRate::B150 => 150,
+            BaudRate::B200 => 200,
+            BaudRate::B300 => 300,
+            BaudRate::B600 => 600,
+            BaudRate::B1200 => 1200,
+            BaudRate::B1800 => 1800,
+            BaudRate::B2400 => 2400,
+            BaudRate::B4800 => 4800,
+            BaudRate::B9600 => 9600,
+            BaudRate::B19200 => 19200,
+            BaudRate::B38400 => 38400,
+            BaudRate::B57600 => 57600,
+            BaudRate::B115200 => 115200,
+            BaudRate::B230400 => 230400,
+            BaudRate::B460800 => 460800,
+            BaudRate::B500000 => 500000,
+            BaudRate::B576000 => 576000,
+            BaudRate::B921600 => 921600,
+            BaudRate::B1000000 => 1000000,
+            BaudRate::B1152000 => 1152000,
+            BaudRate::B1500000 => 1500000,
+            BaudRate::B2000000 => 2000000,
+            BaudRate::B2500000 => 2500000,
+            BaudRate::B3000000 => 3000000,
+            BaudRate::B3500000 => 3500000,
+            BaudRate::B4000000 => 4000000,
+        }
+    }
+}
+
 bitflags! {
     pub struct LocalModes : u32 {
         const ISIG = 0o000001;
@@ -331,6 +1266,7 @@ bitflags! {
 bitflags::bitflags! {
     pub struct OpenFlags: usize {
         // reserve 3 bits for the access mode
+        /// 值为 0，`contains(O_RDONLY)` 总是成立，请使用 [`OpenFlags::access_mode`]
         const O_RDONLY      = 0;
         const O_WRONLY      = 1;
         const O_RDWR        = 2;
@@ -343,6 +1279,7 @@ bitflags::bitflags! {
         const O_NONBLOCK    = 0o4000;
         const O_DSYNC       = 0o10000;
         const O_SYNC        = 0o4010000;
+        /// Linux 上与 `O_SYNC` 同值
         const O_RSYNC       = 0o4010000;
         const O_DIRECTORY   = 0o200000;
         const O_NOFOLLOW    = 0o400000;
@@ -356,6 +1293,161 @@ bitflags::bitflags! {
         const O_TMPFILE     = 0o20200000;
     }
 }
+/// 打开文件时的读写方式，由 [`OpenFlags::O_ACCMODE`] 取出
+#[repr(usize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, IntEnum)]
+pub enum AccessMode {
+    ReadOnly = 0,
+    WriteOnly = 1,
+    ReadWrite = 2,
+}
+
+impl OpenFlags {
+    /// `O_PATH` 可以与之同时使用的标志
+    const O_PATH_FLAGS: OpenFlags = OpenFlags::from_bits_truncate(
+        OpenFlags::O_DIRECTORY.bits()
+            | OpenFlags::O_NOFOLLOW.bits()
+            | OpenFlags::O_PATH.bits()
+            | OpenFlags::O_CLOEXEC.bits(),
+    );
+
+    /// `O_TMPFILE` 中 `O_DIRECTORY` 以外的那一位，单独出现时不合法
+    const __O_TMPFILE: OpenFlags = OpenFlags::from_bits_truncate(0o20000000);
+
+    /// 与 `O_TMPFILE` 相关的位，三者中只能设置 `O_TMPFILE` 包含的两位
+    const O_TMPFILE_MASK: OpenFlags = OpenFlags::from_bits_truncate(
+        OpenFlags::__O_TMPFILE.bits() | OpenFlags::O_DIRECTORY.bits() | OpenFlags::O_CREAT.bits(),
+    );
+
+    /// 是否会创建文件（`O_CREAT` 或 `O_TMPFILE`），此时 `mode` 参数才有意义
+    pub fn will_create(&self) -> bool {
+        self.intersects(OpenFlags::O_CREAT | OpenFlags::__O_TMPFILE)
+    }
+
+    /// 读写方式，`O_ACCMODE` 两位都被设置时返回 `None`
+    pub fn access_mode(&self) -> Option<AccessMode> {
+        AccessMode::try_from(self.bits() & OpenFlags::O_ACCMODE.bits()).ok()
+    }
+
+    /// 是否可读
+    pub fn readable(&self) -> bool {
+        matches!(
+            self.access_mode(),
+            Some(AccessMode::ReadOnly | AccessMode::ReadWrite)
+        )
+    }
+
+    /// 是否可写
+    pub fn writable(&self) -> bool {
+        matches!(
+            self.access_mode(),
+            Some(AccessMode::WriteOnly | AccessMode::ReadWrite)
+        )
+    }
+
+    /// 按照 `openat2` 的规则检查标志组合是否合法，不合法时返回 `EINVAL`：
+    ///
+    /// - `O_TMPFILE` 必须可写，必须包含 `O_DIRECTORY` 且不能与 `O_CREAT` 同时使用；
+    /// - `O_DIRECTORY` 不能与 `O_CREAT` 同时使用；
+    /// - `O_PATH` 只能与 `O_DIRECTORY`、`O_NOFOLLOW` 和 `O_CLOEXEC` 同时使用。
+    ///
+    /// `open`/`openat` 对 `O_PATH` 更宽松，会直接忽略其他标志。
+    pub fn validate(&self) -> Result<(), LinuxErrno> {
+        if self.intersects(OpenFlags::__O_TMPFILE) {
+            if *self & OpenFlags::O_TMPFILE_MASK != OpenFlags::O_TMPFILE || !self.writable() {
+                return Err(LinuxErrno::EINVAL);
+            }
+        } else if self.contains(OpenFlags::O_DIRECTORY | OpenFlags::O_CREAT) {
+            return Err(LinuxErrno::EINVAL);
+        }
+        if self.contains(OpenFlags::O_PATH) && !OpenFlags::O_PATH_FLAGS.contains(*self) {
+            return Err(LinuxErrno::EINVAL);
+        }
+        Ok(())
+    }
+}
+
+bitflags! {
+    /// `openat2` 中路径解析的限制
+    pub struct ResolveFlags: u64 {
+        /// 不允许跨越挂载点
+        const RESOLVE_NO_XDEV = 0x01;
+        /// 不允许经过 /proc 中的 magic link
+        const RESOLVE_NO_MAGICLINKS = 0x02;
+        /// 不允许经过任何符号链接
+        const RESOLVE_NO_SYMLINKS = 0x04;
+        /// 不允许解析到 dirfd 之外
+        const RESOLVE_BENEATH = 0x08;
+        /// 将 dirfd 视为根目录
+        const RESOLVE_IN_ROOT = 0x10;
+        /// 只使用缓存完成解析，否则返回 EAGAIN
+        const RESOLVE_CACHED = 0x20;
+    }
+}
+
+/// `openat2` 的参数
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Pod)]
+pub struct OpenHow {
+    pub flags: u64,
+    pub mode: u64,
+    pub resolve: u64,
+}
+
+impl OpenHow {
+    /// 检查参数是否合法，不合法时返回 `EINVAL`。
+    ///
+    /// 除 [`OpenFlags::validate`] 外，还要求没有未知的标志，只有 `O_CREAT` 或
+    /// `O_TMPFILE` 时才能给出 `mode`，且 `RESOLVE_BENEATH` 与 `RESOLVE_IN_ROOT` 不能同时使用。
+    /// 与 Linux 相同，`RESOLVE_CACHED` 与 `O_CREAT`、`O_TRUNC` 或 `O_TMPFILE`
+    /// 同时使用时返回 `EAGAIN`。
+    pub fn validate(&self) -> Result<(), LinuxErrno> {
+        let flags = usize::try_from(self.flags)
+            .ok()
+            .and_then(OpenFlags::from_bits)
+            .ok_or(LinuxErrno::EINVAL)?;
+        let resolve = ResolveFlags::from_bits(self.resolve).ok_or(LinuxErrno::EINVAL)?;
+        if flags.will_create() {
+            if self.mode & !(InodeMode::PERMISSION_MASK as u64) != 0 {
+                return Err(LinuxErrno::EINVAL);
+            }
+        } else if self.mode != 0 {
+            return Err(LinuxErrno::EINVAL);
+        }
+        if resolve.contains(ResolveFlags::RESOLVE_BENEATH | ResolveFlags::RESOLVE_IN_ROOT) {
+            return Err(LinuxErrno::EINVAL);
+        }
+        flags.validate()?;
+        if resolve.contains(ResolveFlags::RESOLVE_CACHED)
+            && (flags.will_create() || flags.contains(OpenFlags::O_TRUNC))
+        {
+            return Err(LinuxErrno::EAGAIN);
+        }
+        Ok(())
+    }
+
+    pub fn open_flags(&self) -> OpenFlags {
+        OpenFlags::from_bits_truncate(self.flags as usize)
+    }
+
+    pub fn resolve_flags(&self) -> ResolveFlags {
+        ResolveFlags::from_bits_truncate(self.resolve)
+    }
+}
+
+/// `lseek` 的 `whence` 参数
+#[repr(usize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, IntEnum)]
+pub enum Whence {
+    SeekSet = 0,
+    SeekCur = 1,
+    SeekEnd = 2,
+    /// 从指定位置开始查找下一个数据区域
+    SeekData = 3,
+    /// 从指定位置开始查找下一个空洞
+    SeekHole = 4,
+}
+
 /// Enumeration of possible methods to seek within an I/O object.
 ///
 /// It is used by the [`Seek`] trait.
@@ -375,21 +1467,79 @@ pub enum SeekFrom {
     /// It is possible to seek beyond the end of an object, but it's an error to
     /// seek before byte 0.
     Current(i64),
+    /// Sets the offset to the next data region at or after the specified offset.
+    Data(i64),
+    /// Sets the offset to the next hole at or after the specified offset.
+    Hole(i64),
+}
+
+impl SeekFrom {
+    /// 对应的 `whence` 参数
+    pub fn whence(&self) -> Whence {
+        match self {
+            SeekFrom::Start(_) => Whence::SeekSet,
+            SeekFrom::Current(_) => Whence::SeekCur,
+            SeekFrom::End(_) => Whence::SeekEnd,
+            SeekFrom::Data(_) => Whence::SeekData,
+            SeekFrom::Hole(_) => Whence::SeekHole,
+        }
+    }
+
+    /// 根据当前位置 `cur` 与文件大小 `size` 计算新的位置，语义与 Linux 的
+    /// `generic_file_llseek` 相同。
+    ///
+    /// - 结果为负或 `Start` 超出 `i64::MAX` 时返回 `EINVAL`；
+    /// - 计算过程溢出时返回 `EOVERFLOW`；
+    /// - `Data`/`Hole` 的起点为负或不小于 `size` 时返回 `ENXIO`。
+    ///
+    /// 整个文件被视为一段数据，文件末尾是唯一的空洞；支持稀疏文件的文件系统
+    /// 需要自行处理 `Data`/`Hole`。
+    pub fn resolve(self, cur: u64, size: u64) -> Result<u64, LinuxErrno> {
+        let (base, offset) = match self {
+            SeekFrom::Start(offset) => {
+                return match i64::try_from(offset) {
+                    Ok(_) => Ok(offset),
+                    Err(_) => Err(LinuxErrno::EINVAL),
+                };
+            }
+            SeekFrom::Current(offset) => (cur, offset),
+            SeekFrom::End(offset) => (size, offset),
+            SeekFrom::Data(offset) | SeekFrom::Hole(offset)
+                if offset < 0 || offset as u64 >= size =>
+            {
+                return Err(LinuxErrno::ENXIO);
+            }
+            SeekFrom::Data(offset) => return Ok(offset as u64),
+            SeekFrom::Hole(_) => return Ok(size),
+        };
+        let base = i64::try_from(base).map_err(|_| LinuxErrno::EOVERFLOW)?;
+        match base.checked_add(offset) {
+            Some(pos) if pos < 0 => Err(LinuxErrno::EINVAL),
+            Some(pos) => Ok(pos as u64),
+            None => Err(LinuxErrno::EOVERFLOW),
+        }
+    }
 }
 
 impl TryFrom<(usize, usize)> for SeekFrom {
     type Error = ();
 
     fn try_from(value: (usize, usize)) -> Result<Self, Self::Error> {
-        match value {
-            (0, offset) => Ok(SeekFrom::Start(offset as u64)),
-            (1, offset) => Ok(SeekFrom::Current(offset as i64)),
-            (2, offset) => Ok(SeekFrom::End(offset as i64)),
-            _ => Err(()),
+        let (whence, offset) = value;
+        let offset = offset as i64;
+        match Whence::try_from(whence).map_err(|_| ())? {
+            Whence::SeekSet => Ok(SeekFrom::Start(offset as u64)),
+            Whence::SeekCur => Ok(SeekFrom::Current(offset)),
+            Whence::SeekEnd => Ok(SeekFrom::End(offset)),
+            Whence::SeekData => Ok(SeekFrom::Data(offset)),
+            Whence::SeekHole => Ok(SeekFrom::Hole(offset)),
         }
     }
 }
 
+/// 文件名的最大长度，不含结尾的 NUL
+pub const NAME_MAX: usize = 255;
+
 #[repr(C)]
 #[derive(Clone, Pod, Copy)]
 pub struct Dirent64 {
@@ -407,28 +1557,49 @@ pub struct Dirent64 {
 
 impl Debug for Dirent64 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // 文件名紧跟在结构体之后，不在结构体内
         f.debug_struct("Dirent64")
             .field("ino", &self.ino)
             .field("off", &self.off)
             .field("reclen", &self.reclen)
             .field("ty", &DirentType::from_u8(self.ty))
-            .field("name", &core::str::from_utf8(&self.name).unwrap())
-            .finish()
+            .finish_non_exhaustive()
     }
 }
 
 impl Dirent64 {
+    /// 文件名在目录项中的偏移
+    pub const NAME_OFFSET: usize = offset_of!(Self, name);
+
+    /// # Panics
+    ///
+    /// 文件名不合法时 panic，参见 [`Dirent64::try_new`]。
     pub fn new(name: &str, ino: u64, off: i64, ty: DirentType) -> Self {
-        let size = core::mem::size_of::<Self>() + name.as_bytes().len() + 1;
-        // align to 8 bytes
-        let size = (size + 7) & !7;
-        Self {
+        Self::try_new(name, ino, off, ty).expect("invalid dirent name")
+    }
+
+    /// 创建目录项的头部。
+    ///
+    /// 文件名超过 [`NAME_MAX`] 时返回 `ENAMETOOLONG`，含有 NUL 时返回 `EINVAL`。
+    pub fn try_new(name: &str, ino: u64, off: i64, ty: DirentType) -> Result<Self, LinuxErrno> {
+        if name.len() > NAME_MAX {
+            return Err(LinuxErrno::ENAMETOOLONG);
+        }
+        if name.contains('\0') {
+            return Err(LinuxErrno::EINVAL);
+        }
+        Ok(Self {
             ino,
             off,
-            reclen: size as u16,
+            reclen: Self::reclen_for(name.len()) as u16,
             ty: u8::from(ty),
             name: [0; 0],
-        }
+        })
+    }
+
+    /// 文件名长度为 `name_len` 的目录项的长度，包括结尾的 NUL 并按 8 字节对齐
+    pub const fn reclen_for(name_len: usize) -> usize {
+        (Self::NAME_OFFSET + name_len + 1 + 7) & !7
     }
 
     pub fn as_slice(&self) -> &[u8] {
@@ -436,13 +1607,123 @@ impl Dirent64 {
     }
 
     pub fn name_offset(&self) -> usize {
-        offset_of!(Self, name)
+        Self::NAME_OFFSET
     }
 
+    #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
         self.reclen as usize
     }
 }
+
+/// 将目录项按 `getdents64` 的格式依次写入用户缓冲区。
+///
+/// 缓冲区放不下下一项时 [`DirentWriter::push`] 返回 `Ok(false)` 且不写入任何内容，
+/// 调用者应记下该项的位置，下次 `getdents64` 时从这里继续。
+#[derive(Debug)]
+pub struct DirentWriter<'a> {
+    buf: &'a mut [u8],
+    written: usize,
+    overflowed: bool,
+}
+
+impl<'a> DirentWriter<'a> {
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self {
+            buf,
+            written: 0,
+            overflowed: false,
+        }
+    }
+
+    /// 写入一个目录项，`off` 为下一项的位置。缓冲区已满时返回 `Ok(false)`。
+    ///
+    /// 文件名不合法时返回 [`Dirent64::try_new`] 的错误，不写入任何内容。
+    pub fn push(
+        &mut self,
+        ino: u64,
+        off: i64,
+        ty: DirentType,
+        name: &str,
+    ) -> Result<bool, LinuxErrno> {
+        let dirent = Dirent64::try_new(name, ino, off, ty)?;
+        let reclen = dirent.len();
+        let Some(record) = self.buf.get_mut(self.written..self.written + reclen) else {
+            self.overflowed = true;
+            return Ok(false);
+        };
+        let (header, tail) = record.split_at_mut(Dirent64::NAME_OFFSET);
+        header.copy_from_slice(&dirent.as_bytes()[..Dirent64::NAME_OFFSET]);
+        let (name_bytes, padding) = tail.split_at_mut(name.len());
+        name_bytes.copy_from_slice(name.as_bytes());
+        padding.fill(0);
+        self.written += reclen;
+        Ok(true)
+    }
+
+    /// 已写入的字节数
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// 结束写入，返回 `getdents64` 的返回值。
+    ///
+    /// 若缓冲区连第一项都放不下，返回 `EINVAL`。
+    pub fn finish(self) -> Result<usize, LinuxErrno> {
+        if self.written == 0 && self.overflowed {
+            Err(LinuxErrno::EINVAL)
+        } else {
+            Ok(self.written)
+        }
+    }
+}
+
+/// 从 `getdents64` 的缓冲区中解析出的一个目录项
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirentEntry<'a> {
+    pub ino: u64,
+    /// 下一项的位置
+    pub off: i64,
+    pub ty: DirentType,
+    /// 不含结尾 NUL 的文件名
+    pub name: &'a [u8],
+}
+
+/// 依次解析 `getdents64` 缓冲区中的目录项，遇到格式错误的目录项时停止。
+#[derive(Debug, Clone)]
+pub struct DirentIter<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> DirentIter<'a> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+}
+
+impl<'a> Iterator for DirentIter<'a> {
+    type Item = DirentEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header = self.buf.get(..Dirent64::NAME_OFFSET)?;
+        let ino = u64::from_ne_bytes(header[0..8].try_into().unwrap());
+        let off = i64::from_ne_bytes(header[8..16].try_into().unwrap());
+        let reclen = u16::from_ne_bytes(header[16..18].try_into().unwrap()) as usize;
+        let ty = DirentType::from_u8(header[18]);
+        if reclen <= Dirent64::NAME_OFFSET || reclen > self.buf.len() {
+            self.buf = &[];
+            return None;
+        }
+        let name = &self.buf[Dirent64::NAME_OFFSET..reclen];
+        let name = match name.iter().position(|&b| b == 0) {
+            Some(len) => &name[..len],
+            None => name,
+        };
+        self.buf = &self.buf[reclen..];
+        Some(DirentEntry { ino, off, ty, name })
+    }
+}
+
 #[allow(non_camel_case_types)]
 #[repr(u8)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, IntEnum)]
@@ -587,11 +1868,168 @@ bitflags! {
     }
 }
 
+/// 由 [`InodeMode::TYPE_MASK`] 区分的文件类型
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntEnum)]
+pub enum FileType {
+    Unknown = 0,
+    Fifo = 0o010000,
+    Char = 0o020000,
+    Dir = 0o040000,
+    Block = 0o060000,
+    File = 0o100000,
+    Link = 0o120000,
+    Socket = 0o140000,
+}
+
+impl FileType {
+    /// `ls -l` 中表示文件类型的字符
+    pub const fn as_char(self) -> char {
+        match self {
+            FileType::Unknown => '?',
+            FileType::Fifo => 'p',
+            FileType::Char => 'c',
+            FileType::Dir => 'd',
+            FileType::Block => 'b',
+            FileType::File => '-',
+            FileType::Link => 'l',
+            FileType::Socket => 's',
+        }
+    }
+}
+
+impl From<FileType> for DirentType {
+    fn from(ty: FileType) -> Self {
+        match ty {
+            FileType::Unknown => DirentType::DT_UNKNOWN,
+            FileType::Fifo => DirentType::DT_FIFO,
+            FileType::Char => DirentType::DT_CHR,
+            FileType::Dir => DirentType::DT_DIR,
+            FileType::Block => DirentType::DT_BLK,
+            FileType::File => DirentType::DT_REG,
+            FileType::Link => DirentType::DT_LNK,
+            FileType::Socket => DirentType::DT_SOCK,
+        }
+    }
+}
+
+impl From<FileType> for StatxMode {
+    fn from(ty: FileType) -> Self {
+        ty as u32 as StatxMode
+    }
+}
+
+impl From<FileType> for InodeMode {
+    fn from(ty: FileType) -> Self {
+        InodeMode::from_bits_truncate(ty as u32)
+    }
+}
+
+impl InodeMode {
+    /// 权限位，包括 set-user-ID、set-group-ID 与 sticky 位
+    pub const PERMISSION_MASK: u32 = 0o7777;
+
+    /// 文件类型
+    pub fn file_type(&self) -> FileType {
+        FileType::try_from((*self & InodeMode::TYPE_MASK).bits()).unwrap_or(FileType::Unknown)
+    }
+
+    /// 去掉文件类型后的权限位
+    pub fn permissions(&self) -> InodeMode {
+        InodeMode::from_bits_truncate(self.bits() & Self::PERMISSION_MASK)
+    }
+
+    /// 按照 `umask` 清除权限位，文件类型保持不变
+    pub fn apply_umask(&self, umask: u32) -> InodeMode {
+        InodeMode::from_bits_truncate(self.bits() & !(umask & 0o777))
+    }
+
+    /// 按照 POSIX 规则检查 `uid`/`gid`/`groups` 描述的用户对该文件是否有 `access` 权限。
+    ///
+    /// `owner` 与 `group` 为文件的属主。root 总是可以读写，只要任一执行位被设置
+    /// （或者是目录）就可以执行。权限不足时返回 `EACCES`。
+    pub fn permission_check(
+        &self,
+        uid: u32,
+        gid: u32,
+        groups: &[u32],
+        owner: u32,
+        group: u32,
+        access: FaccessatMode,
+    ) -> Result<(), LinuxErrno> {
+        let access = access & (FaccessatMode::R_OK | FaccessatMode::W_OK | FaccessatMode::X_OK);
+        if uid == 0 {
+            let exec_ok = self.file_type() == FileType::Dir
+                || self.intersects(
+                    InodeMode::OWNER_EXEC | InodeMode::GROUP_EXEC | InodeMode::OTHER_EXEC,
+                );
+            return if !access.contains(FaccessatMode::X_OK) || exec_ok {
+                Ok(())
+            } else {
+                Err(LinuxErrno::EACCES)
+            };
+        }
+        let granted = if uid == owner {
+            (self.bits() >> 6) & 0o7
+        } else if gid == group || groups.contains(&group) {
+            (self.bits() >> 3) & 0o7
+        } else {
+            self.bits() & 0o7
+        };
+        if access.bits() & !granted == 0 {
+            Ok(())
+        } else {
+            Err(LinuxErrno::EACCES)
+        }
+    }
+}
+
+/// 以 `ls -l` 的格式显示，如 `drwxr-sr-x`
+impl Display for InodeMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let exec = |set: bool, exec: bool, on: char| match (set, exec) {
+            (true, true) => on,
+            (true, false) => on.to_ascii_uppercase(),
+            (false, true) => 'x',
+            (false, false) => '-',
+        };
+        let bit = |flag: InodeMode, c: char| if self.contains(flag) { c } else { '-' };
+        f.write_char(self.file_type().as_char())?;
+        f.write_char(bit(InodeMode::OWNER_READ, 'r'))?;
+        f.write_char(bit(InodeMode::OWNER_WRITE, 'w'))?;
+        f.write_char(exec(
+            self.contains(InodeMode::SET_UID),
+            self.contains(InodeMode::OWNER_EXEC),
+            's',
+        ))?;
+        f.write_char(bit(InodeMode::GROUP_READ, 'r'))?;
+        f.write_char(bit(InodeMode::GROUP_WRITE, 'w'))?;
+        f.write_char(exec(
+            self.contains(InodeMode::SET_GID),
+            self.contains(InodeMode::GROUP_EXEC),
+            's',
+        ))?;
+        f.write_char(bit(InodeMode::OTHER_READ, 'r'))?;
+        f.write_char(bit(InodeMode::OTHER_WRITE, 'w'))?;
+        f.write_char(exec(
+            self.contains(InodeMode::STICKY),
+            self.contains(InodeMode::OTHER_EXEC),
+            't',
+        ))
+    }
+}
+
 bitflags! {
     pub struct StatFlags:u32{
         const AT_EMPTY_PATH = 0x1000;
         const AT_NO_AUTOMOUNT = 0x800;
         const AT_SYMLINK_NOFOLLOW = 0x100;
+        /// statx: 同步方式与 stat 相同
+        const AT_STATX_SYNC_AS_STAT = 0x0000;
+        /// statx: 强制与远端同步
+        const AT_STATX_FORCE_SYNC = 0x2000;
+        /// statx: 不与远端同步
+        const AT_STATX_DONT_SYNC = 0x4000;
     }
 }
 
@@ -614,10 +2052,31 @@ bitflags! {
         const PROT_READ = 0x1;
         const PROT_WRITE = 0x2;
         const PROT_EXEC = 0x4;
+        /// 页面可以用于原子操作，Linux 忽略该位
+        const PROT_SEM = 0x8;
+        /// `mprotect` 的修改扩展到向下增长的映射的起点
+        const PROT_GROWSDOWN = 0x01000000;
+        /// `mprotect` 的修改扩展到向上增长的映射的终点
+        const PROT_GROWSUP = 0x02000000;
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Debug, IntEnum)]
+impl ProtFlags {
+    /// 解析 `mprotect` 的 `prot` 参数，含有未知的位或同时指定
+    /// `PROT_GROWSDOWN` 与 `PROT_GROWSUP` 时返回 `EINVAL`
+    pub fn parse_mprotect(prot: usize) -> Result<Self, LinuxErrno> {
+        let prot = u32::try_from(prot)
+            .ok()
+            .and_then(Self::from_bits)
+            .ok_or(LinuxErrno::EINVAL)?;
+        if prot.contains(Self::PROT_GROWSDOWN | Self::PROT_GROWSUP) {
+            return Err(LinuxErrno::EINVAL);
+        }
+        Ok(prot)
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, IntEnum)]
 #[repr(u8)]
 pub enum MMapType {
     File = 0x0, // Invalid
@@ -646,6 +2105,176 @@ bitflags! {
     }
 }
 
+/// `mmap` 等系统调用校验参数时使用的页大小
+#[cfg(not(feature = "arch-loongarch64"))]
+pub const PAGE_SIZE: usize = 0x1000;
+/// `mmap` 等系统调用校验参数时使用的页大小，LoongArch 上 Linux 默认使用 16K 的页
+#[cfg(feature = "arch-loongarch64")]
+pub const PAGE_SIZE: usize = 0x4000;
+
+/// `MAP_HUGETLB` 时，`flags` 的这些位给出大页大小的以 2 为底的对数
+pub const MAP_HUGE_SHIFT: u32 = 26;
+pub const MAP_HUGE_MASK: u32 = 0x3f;
+
+const fn is_page_aligned(addr: usize) -> bool {
+    addr & (PAGE_SIZE - 1) == 0
+}
+
+/// 将长度向上对齐到页大小，溢出时返回 `None`
+const fn page_align(len: usize) -> Option<usize> {
+    match len.checked_add(PAGE_SIZE - 1) {
+        Some(len) => Some(len & !(PAGE_SIZE - 1)),
+        None => None,
+    }
+}
+
+/// 校验 `munmap`/`mprotect`/`madvise` 等的地址区间，返回按页对齐后的 `[start, end)`。
+///
+/// `addr` 未对齐时返回 `EINVAL`，区间越过地址空间末尾时返回 `ENOMEM`。
+/// `len` 为 0 时返回空区间，由调用者决定是否合法（`munmap` 返回 `EINVAL`，
+/// `mprotect` 直接成功）。
+pub fn page_range(addr: usize, len: usize) -> Result<Range<usize>, LinuxErrno> {
+    if !is_page_aligned(addr) {
+        return Err(LinuxErrno::EINVAL);
+    }
+    let end = page_align(len)
+        .and_then(|len| addr.checked_add(len))
+        .ok_or(LinuxErrno::ENOMEM)?;
+    Ok(addr..end)
+}
+
+/// 映射的来源
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MmapBacking {
+    /// `MAP_ANONYMOUS`，此时忽略 `fd` 与 `offset`
+    Anonymous,
+    /// 映射文件 `fd` 从 `offset` 开始的部分，`offset` 已按页对齐
+    File { fd: usize, offset: usize },
+}
+
+/// 经过校验的 `mmap` 参数
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MmapRequest {
+    /// 固定映射时是按页对齐的起点，否则只是提示，由内核选择地址
+    pub addr: usize,
+    /// 按页对齐后的长度，不为 0
+    pub len: usize,
+    pub prot: ProtFlags,
+    /// [`MMapType::Shared`] 或 [`MMapType::Private`]，`MAP_SHARED_VALIDATE` 被视为共享映射
+    pub ty: MMapType,
+    /// 除映射类型与大页大小以外的标志，不含未知的位
+    pub flags: MMapFlags,
+    /// `MAP_HUGE_*` 给出的大页大小的以 2 为底的对数，0 表示使用默认的大页大小，
+    /// 只在 `MAP_HUGETLB` 时有意义
+    pub huge_page_shift: u8,
+    pub backing: MmapBacking,
+}
+
+impl MmapRequest {
+    /// 按照 Linux 的规则与顺序校验 `mmap` 的参数：
+    ///
+    /// - `off` 未按页对齐时返回 `EINVAL`；
+    /// - 非匿名映射的 `fd` 为负数时返回 `EBADF`；
+    /// - `len` 为 0 时返回 `EINVAL`，对齐后溢出时返回 `ENOMEM`；
+    /// - 文件偏移加长度溢出时返回 `EOVERFLOW`；
+    /// - 固定映射的地址未对齐时返回 `EINVAL`，越过地址空间末尾时返回 `ENOMEM`；
+    /// - 映射类型不合法时返回 `EINVAL`，匿名映射只能是 `MAP_SHARED` 或 `MAP_PRIVATE`；
+    /// - 文件映射的 `MAP_SHARED_VALIDATE` 带有未知的标志时返回 `EOPNOTSUPP`，其他类型
+    ///   忽略未知的标志，`MAP_SYNC` 只对 `MAP_SHARED_VALIDATE` 有效。
+    ///
+    /// `fd` 是否打开、文件是否支持映射以及访问权限是否满足 `prot` 需要内核自己检查。
+    pub fn parse(
+        addr: usize,
+        len: usize,
+        prot: usize,
+        flags: usize,
+        fd: usize,
+        off: usize,
+    ) -> Result<Self, LinuxErrno> {
+        if !is_page_aligned(off) {
+            return Err(LinuxErrno::EINVAL);
+        }
+        let prot = ProtFlags::from_bits_truncate(prot as u32);
+        // 高 32 位上的标志都是未知的
+        let high_bits = u32::try_from(flags).is_err();
+        let flags = flags as u32;
+        let huge_page_shift = ((flags >> MAP_HUGE_SHIFT) & MAP_HUGE_MASK) as u8;
+        let flags = flags & !(MAP_HUGE_MASK << MAP_HUGE_SHIFT);
+        let rest = flags & !MMAP_TYPE_MASK;
+        let anonymous = rest & MMapFlags::MAP_ANONYMOUS.bits() != 0;
+        if !anonymous && (fd as i32) < 0 {
+            return Err(LinuxErrno::EBADF);
+        }
+        if len == 0 {
+            return Err(LinuxErrno::EINVAL);
+        }
+        let len = page_align(len).ok_or(LinuxErrno::ENOMEM)?;
+        if !anonymous {
+            off.checked_add(len).ok_or(LinuxErrno::EOVERFLOW)?;
+        }
+        let fixed = MMapFlags::MAP_FIXED | MMapFlags::MAP_FIXED_NOREPLACE;
+        if rest & fixed.bits() != 0 {
+            if !is_page_aligned(addr) {
+                return Err(LinuxErrno::EINVAL);
+            }
+            addr.checked_add(len).ok_or(LinuxErrno::ENOMEM)?;
+        }
+        let ty =
+            MMapType::try_from((flags & MMAP_TYPE_MASK) as u8).map_err(|_| LinuxErrno::EINVAL)?;
+        let (ty, flags) = match ty {
+            MMapType::File => return Err(LinuxErrno::EINVAL),
+            MMapType::SharedValidate if anonymous => return Err(LinuxErrno::EINVAL),
+            MMapType::SharedValidate => {
+                let flags = MMapFlags::from_bits(rest)
+                    .filter(|_| !high_bits)
+                    .ok_or(LinuxErrno::EOPNOTSUPP)?;
+                (MMapType::Shared, flags)
+            }
+            ty => (
+                ty,
+                MMapFlags::from_bits_truncate(rest) - MMapFlags::MAP_SYNC,
+            ),
+        };
+        let backing = if anonymous {
+            MmapBacking::Anonymous
+        } else {
+            MmapBacking::File { fd, offset: off }
+        };
+        Ok(Self {
+            addr,
+            len,
+            prot,
+            ty,
+            flags,
+            huge_page_shift,
+            backing,
+        })
+    }
+
+    /// 是否必须映射到 `addr`
+    pub fn is_fixed(&self) -> bool {
+        self.flags
+            .intersects(MMapFlags::MAP_FIXED | MMapFlags::MAP_FIXED_NOREPLACE)
+    }
+
+    /// 固定映射时是否替换区间内已有的映射；为 `false` 且区间内已有映射时
+    /// 应返回 `EEXIST`
+    pub fn replaces_existing(&self) -> bool {
+        self.flags.contains(MMapFlags::MAP_FIXED)
+            && !self.flags.contains(MMapFlags::MAP_FIXED_NOREPLACE)
+    }
+
+    pub fn is_shared(&self) -> bool {
+        self.ty == MMapType::Shared
+    }
+
+    /// 以 `addr` 为起点的区间。固定映射总是返回 `Some`；非固定映射的 `addr`
+    /// 只是提示，越过地址空间末尾时返回 `None`，此时应忽略该提示
+    pub fn range(&self) -> Option<Range<usize>> {
+        Some(self.addr..self.addr.checked_add(self.len)?)
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Pod)]
 pub struct RtcTime {
@@ -659,3 +2288,717 @@ pub struct RtcTime {
     pub yday: u32,  // unused
     pub isdst: u32, // unused
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seek_resolve() {
+        assert_eq!(SeekFrom::Start(10).resolve(5, 100), Ok(10));
+        assert_eq!(SeekFrom::Current(-5).resolve(5, 100), Ok(0));
+        assert_eq!(SeekFrom::End(20).resolve(5, 100), Ok(120));
+        assert_eq!(
+            SeekFrom::Current(-6).resolve(5, 100),
+            Err(LinuxErrno::EINVAL)
+        );
+        assert_eq!(SeekFrom::End(-101).resolve(5, 100), Err(LinuxErrno::EINVAL));
+        assert_eq!(
+            SeekFrom::Start(1 << 63).resolve(0, 0),
+            Err(LinuxErrno::EINVAL)
+        );
+        assert_eq!(
+            SeekFrom::Current(i64::MAX).resolve(1, 0),
+            Err(LinuxErrno::EOVERFLOW)
+        );
+        assert_eq!(
+            SeekFrom::End(0).resolve(0, u64::MAX),
+            Err(LinuxErrno::EOVERFLOW)
+        );
+    }
+
+    #[test]
+    fn seek_data_and_hole() {
+        assert_eq!(SeekFrom::Data(10).resolve(0, 100), Ok(10));
+        assert_eq!(SeekFrom::Hole(10).resolve(0, 100), Ok(100));
+        assert_eq!(SeekFrom::Data(-1).resolve(0, 100), Err(LinuxErrno::ENXIO));
+        assert_eq!(SeekFrom::Data(100).resolve(0, 100), Err(LinuxErrno::ENXIO));
+        assert_eq!(SeekFrom::Hole(100).resolve(0, 100), Err(LinuxErrno::ENXIO));
+    }
+
+    #[test]
+    fn dirent_reclen() {
+        assert_eq!(Dirent64::NAME_OFFSET, 19);
+        assert_eq!(Dirent64::reclen_for(1), 24);
+        assert_eq!(Dirent64::reclen_for(4), 24);
+        assert_eq!(Dirent64::reclen_for(5), 32);
+    }
+
+    #[test]
+    fn dirent_writer_and_iter() {
+        let mut buf = [0xffu8; 64];
+        let mut writer = DirentWriter::new(&mut buf);
+        assert_eq!(writer.push(1, 1, DirentType::DT_DIR, "."), Ok(true));
+        assert_eq!(writer.push(2, 2, DirentType::DT_REG, "hello"), Ok(true));
+        assert_eq!(writer.written(), 56);
+        // 剩余 8 字节放不下下一项，已写入的内容保持不变
+        assert_eq!(writer.push(3, 3, DirentType::DT_LNK, "x"), Ok(false));
+        assert_eq!(writer.finish(), Ok(56));
+
+        let mut iter = DirentIter::new(&buf[..56]);
+        let first = iter.next().unwrap();
+        assert_eq!((first.ino, first.off, first.ty), (1, 1, DirentType::DT_DIR));
+        assert_eq!(first.name, b".");
+        let second = iter.next().unwrap();
+        assert_eq!(
+            (second.ino, second.ty, second.name),
+            (2, DirentType::DT_REG, &b"hello"[..])
+        );
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn dirent_buffer_too_small() {
+        let mut buf = [0u8; 23];
+        let mut writer = DirentWriter::new(&mut buf);
+        assert_eq!(writer.push(1, 1, DirentType::DT_REG, "a"), Ok(false));
+        assert_eq!(writer.finish(), Err(LinuxErrno::EINVAL));
+        assert_eq!(DirentWriter::new(&mut []).finish(), Ok(0));
+    }
+
+    #[test]
+    fn dirent_rejects_bad_names() {
+        let long = "a".repeat(NAME_MAX + 1);
+        let mut buf = alloc::vec![0u8; 200000];
+        let mut writer = DirentWriter::new(&mut buf);
+        assert_eq!(
+            writer.push(1, 1, DirentType::DT_REG, &"a".repeat(65536)),
+            Err(LinuxErrno::ENAMETOOLONG)
+        );
+        assert_eq!(
+            writer.push(1, 1, DirentType::DT_REG, &long),
+            Err(LinuxErrno::ENAMETOOLONG)
+        );
+        assert_eq!(
+            writer.push(1, 1, DirentType::DT_REG, "a\0b"),
+            Err(LinuxErrno::EINVAL)
+        );
+        assert_eq!(writer.written(), 0);
+        assert_eq!(
+            writer.push(1, 1, DirentType::DT_REG, &long[..NAME_MAX]),
+            Ok(true)
+        );
+        assert_eq!(writer.written(), Dirent64::reclen_for(NAME_MAX));
+        assert_eq!(
+            Dirent64::try_new(&long, 1, 1, DirentType::DT_REG).map(|d| d.len()),
+            Err(LinuxErrno::ENAMETOOLONG)
+        );
+    }
+
+    #[test]
+    fn dirent_iter_rejects_bad_reclen() {
+        let mut buf = [0u8; 48];
+        let mut writer = DirentWriter::new(&mut buf);
+        assert_eq!(writer.push(1, 1, DirentType::DT_REG, "a"), Ok(true));
+        assert_eq!(writer.push(2, 2, DirentType::DT_REG, "b"), Ok(true));
+        // 第二项的长度超出缓冲区
+        buf[24 + 16..24 + 18].copy_from_slice(&64u16.to_ne_bytes());
+        let mut iter = DirentIter::new(&buf);
+        assert_eq!(iter.next().unwrap().name, b"a");
+        assert_eq!(iter.next(), None);
+        // 长度不超过头部的目录项同样视为格式错误
+        buf[16..18].copy_from_slice(&8u16.to_ne_bytes());
+        assert_eq!(DirentIter::new(&buf).next(), None);
+        assert_eq!(DirentIter::new(&buf[..10]).next(), None);
+    }
+
+    #[test]
+    fn permission_check_classes() {
+        use FaccessatMode as M;
+        let mode = InodeMode::from_bits_truncate(0o100640);
+        let check = |uid, gid, groups: &[u32], access| {
+            mode.permission_check(uid, gid, groups, 1000, 100, access)
+        };
+        // 属主
+        assert_eq!(check(1000, 1, &[], M::R_OK | M::W_OK), Ok(()));
+        assert_eq!(check(1000, 1, &[], M::X_OK), Err(LinuxErrno::EACCES));
+        // 属组，包括附加组
+        assert_eq!(check(2000, 100, &[], M::R_OK), Ok(()));
+        assert_eq!(check(2000, 1, &[7, 100], M::R_OK), Ok(()));
+        assert_eq!(check(2000, 100, &[], M::W_OK), Err(LinuxErrno::EACCES));
+        // 其他用户
+        assert_eq!(check(2000, 1, &[], M::R_OK), Err(LinuxErrno::EACCES));
+        assert_eq!(check(2000, 1, &[], M::F_OK), Ok(()));
+        // 属主只看属主的权限位，即使其他用户的权限更多
+        let mode = InodeMode::from_bits_truncate(0o100007);
+        assert_eq!(
+            mode.permission_check(1000, 100, &[], 1000, 100, M::R_OK),
+            Err(LinuxErrno::EACCES)
+        );
+        assert_eq!(
+            mode.permission_check(2000, 1, &[], 1000, 100, M::R_OK),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn permission_check_root() {
+        use FaccessatMode as M;
+        let check = |mode, access| {
+            InodeMode::from_bits_truncate(mode).permission_check(0, 0, &[], 1000, 100, access)
+        };
+        assert_eq!(check(0o100000, M::R_OK | M::W_OK), Ok(()));
+        assert_eq!(check(0o100600, M::X_OK), Err(LinuxErrno::EACCES));
+        assert_eq!(check(0o100001, M::X_OK), Ok(()));
+        assert_eq!(check(0o040000, M::X_OK), Ok(()));
+    }
+
+    #[test]
+    fn inode_mode_display() {
+        let mode = InodeMode::from_bits_truncate(0o042755);
+        assert_eq!(mode.file_type(), FileType::Dir);
+        assert_eq!(alloc::format!("{}", mode), "drwxr-sr-x");
+        assert_eq!(
+            alloc::format!("{}", InodeMode::from_bits_truncate(0o101644)),
+            "-rw-r--r-T"
+        );
+        assert_eq!(
+            InodeMode::from_bits_truncate(0o100666)
+                .apply_umask(0o022)
+                .bits(),
+            0o100644
+        );
+    }
+
+    #[test]
+    fn open_flags_tmpfile() {
+        let tmpfile = OpenFlags::O_TMPFILE | OpenFlags::O_RDWR;
+        assert_eq!(tmpfile.validate(), Ok(()));
+        // 缺少 O_DIRECTORY 的 __O_TMPFILE
+        let bare = OpenFlags::from_bits_truncate(0o20000002);
+        assert_eq!(bare.validate(), Err(LinuxErrno::EINVAL));
+        assert_eq!(
+            (tmpfile | OpenFlags::O_CREAT).validate(),
+            Err(LinuxErrno::EINVAL)
+        );
+        assert_eq!(OpenFlags::O_TMPFILE.validate(), Err(LinuxErrno::EINVAL));
+        assert_eq!(
+            (OpenFlags::O_DIRECTORY | OpenFlags::O_CREAT).validate(),
+            Err(LinuxErrno::EINVAL)
+        );
+        assert_eq!(OpenFlags::O_DIRECTORY.validate(), Ok(()));
+        assert_eq!(
+            (OpenFlags::O_PATH | OpenFlags::O_RDWR).validate(),
+            Err(LinuxErrno::EINVAL)
+        );
+    }
+
+    #[test]
+    fn open_how_mode() {
+        let how = |flags: OpenFlags, mode| OpenHow {
+            flags: flags.bits() as u64,
+            mode,
+            resolve: 0,
+        };
+        assert_eq!(how(OpenFlags::O_DIRECTORY, 0).validate(), Ok(()));
+        assert_eq!(
+            how(OpenFlags::O_DIRECTORY, 0o644).validate(),
+            Err(LinuxErrno::EINVAL)
+        );
+        assert_eq!(how(OpenFlags::O_CREAT, 0o644).validate(), Ok(()));
+        assert_eq!(
+            how(OpenFlags::O_CREAT, 0o170644).validate(),
+            Err(LinuxErrno::EINVAL)
+        );
+        assert_eq!(
+            how(OpenFlags::O_TMPFILE | OpenFlags::O_WRONLY, 0o600).validate(),
+            Ok(())
+        );
+        let both = OpenHow {
+            resolve: (ResolveFlags::RESOLVE_BENEATH | ResolveFlags::RESOLVE_IN_ROOT).bits(),
+            ..how(OpenFlags::O_RDONLY, 0)
+        };
+        assert_eq!(both.validate(), Err(LinuxErrno::EINVAL));
+    }
+
+    #[test]
+    fn open_how_resolve_cached() {
+        let cached = |flags: OpenFlags, mode| OpenHow {
+            flags: flags.bits() as u64,
+            mode,
+            resolve: ResolveFlags::RESOLVE_CACHED.bits(),
+        };
+        assert_eq!(cached(OpenFlags::O_RDONLY, 0).validate(), Ok(()));
+        assert_eq!(
+            cached(OpenFlags::O_WRONLY | OpenFlags::O_APPEND, 0).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            cached(OpenFlags::O_CREAT, 0o644).validate(),
+            Err(LinuxErrno::EAGAIN)
+        );
+        assert_eq!(
+            cached(OpenFlags::O_WRONLY | OpenFlags::O_TRUNC, 0).validate(),
+            Err(LinuxErrno::EAGAIN)
+        );
+        assert_eq!(
+            cached(OpenFlags::O_TMPFILE | OpenFlags::O_RDWR, 0o600).validate(),
+            Err(LinuxErrno::EAGAIN)
+        );
+        // 参数本身不合法时仍返回 EINVAL
+        assert_eq!(
+            cached(OpenFlags::O_TMPFILE, 0o600).validate(),
+            Err(LinuxErrno::EINVAL)
+        );
+    }
+
+    const ANON_PRIVATE: usize =
+        MMapFlags::MAP_ANONYMOUS.bits() as usize | MMapType::Private as usize;
+    const NO_FD: usize = -1isize as usize;
+
+    #[test]
+    fn mmap_anonymous() {
+        let request = MmapRequest::parse(0, PAGE_SIZE + 1, 3, ANON_PRIVATE, NO_FD, 0).unwrap();
+        assert_eq!(request.len, 2 * PAGE_SIZE);
+        assert_eq!(request.prot, ProtFlags::PROT_READ | ProtFlags::PROT_WRITE);
+        assert_eq!(request.ty, MMapType::Private);
+        assert_eq!(request.flags, MMapFlags::MAP_ANONYMOUS);
+        assert_eq!(request.backing, MmapBacking::Anonymous);
+        assert!(!request.is_fixed() && !request.is_shared());
+        // 非固定映射的地址只是提示，不要求对齐
+        let request = MmapRequest::parse(1, 1, 0, ANON_PRIVATE, NO_FD, 0).unwrap();
+        assert_eq!(request.range(), Some(1..1 + PAGE_SIZE));
+        // 越过地址空间末尾的提示可以被接受，但没有对应的区间
+        let request = MmapRequest::parse(usize::MAX - 1, 1, 0, ANON_PRIVATE, NO_FD, 0).unwrap();
+        assert_eq!(request.range(), None);
+    }
+
+    #[test]
+    fn mmap_file_fixed() {
+        let flags = MMapType::Shared as usize | MMapFlags::MAP_FIXED_NOREPLACE.bits() as usize;
+        let request = MmapRequest::parse(2 * PAGE_SIZE, 1, 1, flags, 4, 3 * PAGE_SIZE).unwrap();
+        assert!(request.is_fixed() && !request.replaces_existing() && request.is_shared());
+        assert_eq!(
+            request.backing,
+            MmapBacking::File {
+                fd: 4,
+                offset: 3 * PAGE_SIZE
+            }
+        );
+        assert_eq!(request.range(), Some(2 * PAGE_SIZE..3 * PAGE_SIZE));
+        let flags = MMapType::Private as usize | MMapFlags::MAP_FIXED.bits() as usize;
+        assert!(MmapRequest::parse(0, 1, 1, flags, 4, 0)
+            .unwrap()
+            .replaces_existing());
+    }
+
+    #[test]
+    fn mmap_einval() {
+        assert_eq!(
+            MmapRequest::parse(0, 0, 3, ANON_PRIVATE, NO_FD, 0),
+            Err(LinuxErrno::EINVAL)
+        );
+        assert_eq!(
+            MmapRequest::parse(0, 1, 3, MMapType::Private as usize, 3, 1),
+            Err(LinuxErrno::EINVAL)
+        );
+        // 缺少映射类型或类型未知
+        let anon = MMapFlags::MAP_ANONYMOUS.bits() as usize;
+        assert_eq!(
+            MmapRequest::parse(0, 1, 3, anon, NO_FD, 0),
+            Err(LinuxErrno::EINVAL)
+        );
+        assert_eq!(
+            MmapRequest::parse(0, 1, 3, anon | 0xf, NO_FD, 0),
+            Err(LinuxErrno::EINVAL)
+        );
+        let fixed = ANON_PRIVATE | MMapFlags::MAP_FIXED.bits() as usize;
+        assert_eq!(
+            MmapRequest::parse(PAGE_SIZE + 1, 1, 3, fixed, NO_FD, 0),
+            Err(LinuxErrno::EINVAL)
+        );
+    }
+
+    #[test]
+    fn mmap_ebadf_enomem_eoverflow() {
+        assert_eq!(
+            MmapRequest::parse(0, 1, 1, MMapType::Private as usize, NO_FD, 0),
+            Err(LinuxErrno::EBADF)
+        );
+        assert_eq!(
+            MmapRequest::parse(0, usize::MAX, 3, ANON_PRIVATE, NO_FD, 0),
+            Err(LinuxErrno::ENOMEM)
+        );
+        let fixed = ANON_PRIVATE | MMapFlags::MAP_FIXED.bits() as usize;
+        assert_eq!(
+            MmapRequest::parse(usize::MAX & !(PAGE_SIZE - 1), 1, 3, fixed, NO_FD, 0),
+            Err(LinuxErrno::ENOMEM)
+        );
+        let off = usize::MAX & !(PAGE_SIZE - 1);
+        assert_eq!(
+            MmapRequest::parse(0, 1, 1, MMapType::Private as usize, 3, off),
+            Err(LinuxErrno::EOVERFLOW)
+        );
+        // 匿名映射忽略文件偏移
+        assert!(MmapRequest::parse(0, 1, 1, ANON_PRIVATE, NO_FD, off).is_ok());
+    }
+
+    #[test]
+    fn mmap_shared_validate() {
+        let validate = MMapType::SharedValidate as usize;
+        let sync = MMapFlags::MAP_SYNC.bits() as usize;
+        let request = MmapRequest::parse(0, 1, 1, validate | sync, 3, 0).unwrap();
+        assert_eq!(
+            (request.ty, request.flags),
+            (MMapType::Shared, MMapFlags::MAP_SYNC)
+        );
+        assert_eq!(
+            MmapRequest::parse(0, 1, 1, validate | 0x400000, 3, 0),
+            Err(LinuxErrno::EOPNOTSUPP)
+        );
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(
+            MmapRequest::parse(0, 1, 1, validate | 1 << 40, 3, 0),
+            Err(LinuxErrno::EOPNOTSUPP)
+        );
+        // 其他类型忽略未知的标志与 MAP_SYNC
+        let shared = MMapType::Shared as usize;
+        let request = MmapRequest::parse(0, 1, 1, shared | sync | 0x400000, 3, 0).unwrap();
+        assert!(request.flags.is_empty());
+        // 与 Linux 相同，先检查长度再检查标志
+        assert_eq!(
+            MmapRequest::parse(0, 0, 1, validate | 0x400000, 3, 0),
+            Err(LinuxErrno::EINVAL)
+        );
+        assert_eq!(
+            MmapRequest::parse(0, 1, 1, validate | 0x400000, NO_FD, 0),
+            Err(LinuxErrno::EBADF)
+        );
+    }
+
+    #[test]
+    fn mmap_huge_page_size() {
+        let huge = (MMapFlags::MAP_ANONYMOUS | MMapFlags::MAP_HUGETLB).bits() as usize
+            | 21 << MAP_HUGE_SHIFT;
+        for ty in [MMapType::Private, MMapType::Shared] {
+            let request = MmapRequest::parse(0, 1, 3, ty as usize | huge, NO_FD, 0).unwrap();
+            assert_eq!(request.huge_page_shift, 21);
+            assert_eq!(
+                request.flags,
+                MMapFlags::MAP_ANONYMOUS | MMapFlags::MAP_HUGETLB
+            );
+        }
+        // 大页大小的位不被 MAP_SHARED_VALIDATE 视为未知的标志
+        let validate = MMapType::SharedValidate as usize | 21 << MAP_HUGE_SHIFT;
+        let request = MmapRequest::parse(0, 1, 3, validate, 3, 0).unwrap();
+        assert_eq!(request.huge_page_shift, 21);
+        assert!(request.flags.is_empty());
+    }
+
+    #[test]
+    fn mmap_anonymous_shared_validate() {
+        // 匿名映射只支持 MAP_SHARED 与 MAP_PRIVATE
+        let anon = MMapFlags::MAP_ANONYMOUS.bits() as usize;
+        let validate = MMapType::SharedValidate as usize;
+        assert_eq!(
+            MmapRequest::parse(0, 1, 3, anon | validate, NO_FD, 0),
+            Err(LinuxErrno::EINVAL)
+        );
+        assert_eq!(
+            MmapRequest::parse(0, 1, 3, anon | validate | 0x400000, NO_FD, 0),
+            Err(LinuxErrno::EINVAL)
+        );
+        let shared = MmapRequest::parse(0, 1, 3, anon | MMapType::Shared as usize, NO_FD, 0);
+        assert!(shared.unwrap().is_shared());
+    }
+
+    #[test]
+    fn munmap_and_mprotect_ranges() {
+        assert_eq!(page_range(PAGE_SIZE, 1), Ok(PAGE_SIZE..2 * PAGE_SIZE));
+        assert_eq!(page_range(PAGE_SIZE, 0), Ok(PAGE_SIZE..PAGE_SIZE));
+        assert_eq!(page_range(PAGE_SIZE + 1, 1), Err(LinuxErrno::EINVAL));
+        assert_eq!(
+            page_range(usize::MAX & !(PAGE_SIZE - 1), PAGE_SIZE),
+            Err(LinuxErrno::ENOMEM)
+        );
+        assert_eq!(ProtFlags::parse_mprotect(0x10), Err(LinuxErrno::EINVAL));
+        assert_eq!(
+            ProtFlags::parse_mprotect(0x03000000),
+            Err(LinuxErrno::EINVAL)
+        );
+        assert_eq!(
+            ProtFlags::parse_mprotect(0x01000003),
+            Ok(ProtFlags::PROT_GROWSDOWN | ProtFlags::PROT_READ | ProtFlags::PROT_WRITE)
+        );
+    }
+
+    #[test]
+    fn seek_from_whence() {
+        assert_eq!(
+            SeekFrom::try_from((1, -3isize as usize)),
+            Ok(SeekFrom::Current(-3))
+        );
+        assert_eq!(SeekFrom::try_from((4, 7)), Ok(SeekFrom::Hole(7)));
+        assert_eq!(SeekFrom::try_from((5, 0)), Err(()));
+        assert_eq!(SeekFrom::Data(0).whence(), Whence::SeekData);
+    }
+
+    #[test]
+    fn device_numbers() {
+        assert_eq!(makedev(8, 1), 0x801);
+        assert_eq!(makedev(0x12345, 0x6789a), 0x0001_2000_6783_459a);
+        for (major, minor) in [(0, 0), (8, 1), (0x12345, 0x6789a), (u32::MAX, u32::MAX)] {
+            let dev = makedev(major, minor);
+            assert_eq!((dev_major(dev), dev_minor(dev)), (major, minor));
+        }
+    }
+
+    #[test]
+    fn statx_from_file_stat() {
+        let stat = FileStat {
+            st_dev: makedev(8, 1),
+            st_ino: 42,
+            st_mode: 0o100644,
+            st_nlink: 2,
+            st_uid: 1000,
+            st_gid: 100,
+            st_rdev: makedev(136, 3),
+            st_size: 5000,
+            st_blksize: 4096,
+            st_blocks: 16,
+            st_atime_sec: 1,
+            st_atime_nsec: 2,
+            st_mtime_sec: 3,
+            st_mtime_nsec: 4,
+            st_ctime_sec: 5,
+            st_ctime_nsec: 6,
+            ..Default::default()
+        };
+        let statx = Statx::from(stat);
+        assert_eq!(statx.stx_mask, StatxMask::STATX_BASIC_STATS.bits());
+        assert_eq!(statx.stx_mode, 0o100644);
+        assert_eq!((statx.stx_ino, statx.stx_nlink), (42, 2));
+        assert_eq!((statx.stx_uid, statx.stx_gid), (1000, 100));
+        assert_eq!((statx.stx_size, statx.stx_blocks), (5000, 16));
+        assert_eq!(statx.stx_blksize, 4096);
+        assert_eq!((statx.stx_dev_major, statx.stx_dev_minor), (8, 1));
+        assert_eq!((statx.stx_rdev_major, statx.stx_rdev_minor), (136, 3));
+        assert_eq!(statx.stx_atime, StatxTimestamp::new(1, 2));
+        assert_eq!(statx.stx_mtime, StatxTimestamp::new(3, 4));
+        assert_eq!(statx.stx_ctime, StatxTimestamp::new(5, 6));
+        // FileStat 中没有创建时间
+        assert_eq!(statx.stx_btime, StatxTimestamp::default());
+    }
+
+    #[test]
+    fn fcntl_decode_args() {
+        let decode = FcntlArg::decode;
+        assert_eq!(decode(Fcntl64Cmd::F_DUPFD, 10), Ok(FcntlArg::Fd(10)));
+        assert_eq!(decode(Fcntl64Cmd::F_DUPFD_CLOEXEC, 3), Ok(FcntlArg::Fd(3)));
+        assert_eq!(decode(Fcntl64Cmd::DUPFD_QUERY, 4), Ok(FcntlArg::Fd(4)));
+        assert_eq!(
+            decode(Fcntl64Cmd::F_SETFD, FD_CLOEXEC | 2),
+            Ok(FcntlArg::CloseOnExec(true))
+        );
+        assert_eq!(
+            decode(Fcntl64Cmd::F_SETFD, 2),
+            Ok(FcntlArg::CloseOnExec(false))
+        );
+        // F_SETFL 忽略无法识别的位
+        assert_eq!(
+            decode(Fcntl64Cmd::F_SETFL, OpenFlags::O_NONBLOCK.bits() | 1 << 30),
+            Ok(FcntlArg::StatusFlags(OpenFlags::O_NONBLOCK))
+        );
+        assert_eq!(
+            decode(Fcntl64Cmd::SETOWN, -42i32 as usize),
+            Ok(FcntlArg::Owner(-42))
+        );
+        assert_eq!(
+            decode(Fcntl64Cmd::GETOWN_EX, 0x1000),
+            Ok(FcntlArg::OwnerEx(0x1000))
+        );
+        assert_eq!(
+            decode(Fcntl64Cmd::SETOWN_EX, 0x1000),
+            Ok(FcntlArg::OwnerEx(0x1000))
+        );
+        assert_eq!(
+            decode(Fcntl64Cmd::GETOWNER_UIDS, 0x1000),
+            Ok(FcntlArg::OwnerUids(0x1000))
+        );
+        assert_eq!(
+            decode(Fcntl64Cmd::NOTIFY, 0x80000001),
+            Ok(FcntlArg::Notify(0x80000001))
+        );
+        assert_eq!(
+            decode(Fcntl64Cmd::SETPIPE_SZ, 65536),
+            Ok(FcntlArg::PipeSize(65536))
+        );
+        for cmd in [
+            Fcntl64Cmd::GET_RW_HINT,
+            Fcntl64Cmd::SET_RW_HINT,
+            Fcntl64Cmd::GET_FILE_RW_HINT,
+            Fcntl64Cmd::SET_FILE_RW_HINT,
+        ] {
+            assert_eq!(decode(cmd, 0x2000), Ok(FcntlArg::RwHint(0x2000)));
+        }
+        for cmd in [
+            Fcntl64Cmd::F_GETFD,
+            Fcntl64Cmd::F_GETFL,
+            Fcntl64Cmd::GETOWN,
+            Fcntl64Cmd::GETSIG,
+            Fcntl64Cmd::GETLEASE,
+            Fcntl64Cmd::CREATED_QUERY,
+            Fcntl64Cmd::CANCELLK,
+            Fcntl64Cmd::GETPIPE_SZ,
+            Fcntl64Cmd::GET_SEALS,
+        ] {
+            assert_eq!(decode(cmd, 123), Ok(FcntlArg::None));
+        }
+    }
+
+    #[test]
+    fn fcntl_decode_lock_pointers() {
+        for cmd in [
+            Fcntl64Cmd::GETLK,
+            Fcntl64Cmd::SETLK,
+            Fcntl64Cmd::SETLKW,
+            Fcntl64Cmd::OFD_GETLK,
+            Fcntl64Cmd::OFD_SETLK,
+            Fcntl64Cmd::OFD_SETLKW,
+        ] {
+            assert_eq!(
+                FcntlArg::decode(cmd, 0x7fff_0000),
+                Ok(FcntlArg::Flock(0x7fff_0000))
+            );
+        }
+    }
+
+    #[test]
+    fn fcntl_decode_errors() {
+        let decode = FcntlArg::decode;
+        assert_eq!(decode(Fcntl64Cmd::SETSIG, 0), Ok(FcntlArg::Signal(None)));
+        assert_eq!(
+            decode(Fcntl64Cmd::SETSIG, 10),
+            Ok(FcntlArg::Signal(Some(SignalNumber::SIGUSR1)))
+        );
+        assert_eq!(decode(Fcntl64Cmd::SETSIG, 65), Err(LinuxErrno::EINVAL));
+        assert_eq!(decode(Fcntl64Cmd::SETSIG, 0x10a), Err(LinuxErrno::EINVAL));
+        assert_eq!(
+            decode(Fcntl64Cmd::SETLEASE, 1),
+            Ok(FcntlArg::Lease(FlockType::F_WRLCK))
+        );
+        assert_eq!(
+            decode(Fcntl64Cmd::SETLEASE, 2),
+            Ok(FcntlArg::Lease(FlockType::F_UNLCK))
+        );
+        assert_eq!(decode(Fcntl64Cmd::SETLEASE, 3), Err(LinuxErrno::EINVAL));
+        assert_eq!(
+            decode(Fcntl64Cmd::SETLEASE, 0x10001),
+            Err(LinuxErrno::EINVAL)
+        );
+        let seals = SealFlags::F_SEAL_SEAL | SealFlags::F_SEAL_WRITE;
+        assert_eq!(
+            decode(Fcntl64Cmd::ADD_SEALS, seals.bits() as usize),
+            Ok(FcntlArg::Seals(seals))
+        );
+        // 未知的封印位
+        assert_eq!(decode(Fcntl64Cmd::ADD_SEALS, 0x40), Err(LinuxErrno::EINVAL));
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(
+            decode(Fcntl64Cmd::ADD_SEALS, 1 << 32),
+            Err(LinuxErrno::EINVAL)
+        );
+    }
+
+    #[test]
+    fn termios_default() {
+        // 与 Linux 上 `stty sane` 后 `stty -g` 的输出一致
+        let termios = Termios::default();
+        assert_eq!(termios.iflag, 0x6d02);
+        assert_eq!(termios.oflag, 0x5);
+        assert_eq!(termios.cflag, 0x4bf);
+        assert_eq!(termios.lflag, 0x8a3b);
+        assert_eq!(
+            termios.cc[..17],
+            [
+                0x03, 0x1c, 0x7f, 0x15, 0x04, 0x00, 0x01, 0x00, 0x11, 0x13, 0x1a, 0xff, 0x12, 0x0f,
+                0x17, 0x16, 0xff
+            ]
+        );
+        assert!(termios.cc[17..].iter().all(|&c| c == 0));
+        assert_eq!(termios.cfgetospeed(), BaudRate::B38400);
+        assert_eq!(termios.cfgetispeed(), BaudRate::B38400);
+    }
+
+    #[test]
+    fn termios_speed() {
+        let mut termios = Termios::default();
+        termios.cfsetospeed(BaudRate::B9600);
+        assert_eq!(termios.cflag & CBAUD, 0o15);
+        assert_eq!(termios.cflag & !CBAUD, 0o2260);
+        termios.cfsetispeed(BaudRate::B115200);
+        assert_eq!(termios.cflag & CIBAUD, 0o10002 << 16);
+        assert_eq!(termios.cflag & CBAUD, 0o15);
+        assert_eq!(termios.cfgetospeed(), BaudRate::B9600);
+        assert_eq!(termios.cfgetispeed(), BaudRate::B115200);
+        assert_eq!(termios.control_modes().bits(), 0o2260);
+        // B0 表示输入波特率与输出波特率相同
+        termios.cfsetispeed(BaudRate::B0);
+        assert_eq!(termios.cflag & CIBAUD, 0);
+        assert_eq!(termios.cfgetispeed(), BaudRate::B9600);
+        // 修改控制模式不影响波特率
+        termios.cfsetispeed(BaudRate::B1200);
+        termios.set_control_modes(ControlModes::CS7 | ControlModes::PARENB);
+        assert_eq!(termios.cflag, 0o40 | 0o400 | 0o15 | 0o11 << 16);
+    }
+
+    #[test]
+    fn termios_make_raw() {
+        let mut termios = Termios {
+            iflag: InputModes::all().bits(),
+            oflag: OutputModes::all().bits(),
+            lflag: LocalModes::all().bits(),
+            ..Default::default()
+        };
+        termios.set_control_modes((ControlModes::all() - ControlModes::CSIZE) | ControlModes::CS7);
+        termios.cfsetispeed(BaudRate::B1200);
+        termios.set_cc(ControlChar::VMIN, 5);
+        termios.set_cc(ControlChar::VTIME, 3);
+        termios.cfmakeraw();
+        // IGNBRK BRKINT PARMRK ISTRIP INLCR IGNCR ICRNL IXON
+        assert_eq!(termios.iflag, InputModes::all().bits() & !0o2753);
+        // OPOST
+        assert_eq!(termios.oflag, OutputModes::all().bits() & !0o1);
+        // ECHO ECHONL ICANON ISIG IEXTEN
+        assert_eq!(termios.lflag, LocalModes::all().bits() & !0o100113);
+        // PARENB 被清除，字符长度设为 CS8
+        assert_eq!(
+            termios.control_modes(),
+            ControlModes::all() - ControlModes::PARENB
+        );
+        assert_eq!(termios.cfgetospeed(), BaudRate::B38400);
+        assert_eq!(termios.cfgetispeed(), BaudRate::B1200);
+        assert_eq!(termios.cc(ControlChar::VMIN), 1);
+        assert_eq!(termios.cc(ControlChar::VTIME), 0);
+        assert_eq!(termios.cc(ControlChar::VINTR), 0x03);
+    }
+
+    #[test]
+    fn winsize_update() {
+        let mut winsize = WinSize::default();
+        assert_eq!((winsize.rows(), winsize.cols()), (24, 80));
+        assert_eq!(winsize.update(WinSize::new(24, 80)), None);
+        assert_eq!(
+            winsize.update(WinSize::new(50, 132)),
+            Some(SignalNumber::SIGWINCH)
+        );
+        assert_eq!((winsize.rows(), winsize.cols()), (50, 132));
+        assert_eq!(winsize.update(WinSize::new(50, 132)), None);
+        // 只有像素大小改变时同样通知
+        assert_eq!(
+            winsize.update(WinSize::with_pixels(50, 132, 1056, 800)),
+            Some(SignalNumber::SIGWINCH)
+        );
+        assert_eq!((winsize.xpixel(), winsize.ypixel()), (1056, 800));
+    }
+}
@@ -0,0 +1,361 @@
+//! ioctl 请求号的编码与常用的 ioctl 命令。
+//!
+//! 请求号的布局与 `asm-generic/ioctl.h` 相同，本库支持的体系结构都使用这种布局：
+//! 从低位到高位依次为 8 位的序号、8 位的类型、14 位的参数大小与 2 位的方向。
+#![allow(non_snake_case)]
+
+use core::mem::size_of;
+
+use int_enum::IntEnum;
+use pod::Pod;
+
+use crate::io::{RtcTime, TeletypeCommand};
+
+pub const _IOC_NRBITS: u32 = 8;
+pub const _IOC_TYPEBITS: u32 = 8;
+pub const _IOC_SIZEBITS: u32 = 14;
+pub const _IOC_DIRBITS: u32 = 2;
+
+pub const _IOC_NRSHIFT: u32 = 0;
+pub const _IOC_TYPESHIFT: u32 = _IOC_NRSHIFT + _IOC_NRBITS;
+pub const _IOC_SIZESHIFT: u32 = _IOC_TYPESHIFT + _IOC_TYPEBITS;
+pub const _IOC_DIRSHIFT: u32 = _IOC_SIZESHIFT + _IOC_SIZEBITS;
+
+/// 没有参数，或参数不是指针
+pub const _IOC_NONE: u32 = 0;
+/// 用户向内核写入参数
+pub const _IOC_WRITE: u32 = 1;
+/// 内核向用户返回参数
+pub const _IOC_READ: u32 = 2;
+
+/// 由方向、类型、序号与参数大小构造请求号，`size` 超过 14 位时编译失败
+pub const fn _IOC(dir: u32, ty: u8, nr: u8, size: usize) -> u32 {
+    assert!(dir < 1 << _IOC_DIRBITS, "ioctl direction out of range");
+    assert!(size < 1 << _IOC_SIZEBITS, "ioctl argument too large");
+    (dir << _IOC_DIRSHIFT)
+        | ((ty as u32) << _IOC_TYPESHIFT)
+        | ((nr as u32) << _IOC_NRSHIFT)
+        | ((size as u32) << _IOC_SIZESHIFT)
+}
+
+/// 没有参数的请求
+pub const fn _IO(ty: u8, nr: u8) -> u32 {
+    _IOC(_IOC_NONE, ty, nr, 0)
+}
+
+/// 内核向用户返回一个 `T`
+pub const fn _IOR<T>(ty: u8, nr: u8) -> u32 {
+    _IOC(_IOC_READ, ty, nr, size_of::<T>())
+}
+
+/// 用户向内核传入一个 `T`
+pub const fn _IOW<T>(ty: u8, nr: u8) -> u32 {
+    _IOC(_IOC_WRITE, ty, nr, size_of::<T>())
+}
+
+/// 用户传入一个 `T`，内核修改后返回
+pub const fn _IOWR<T>(ty: u8, nr: u8) -> u32 {
+    _IOC(_IOC_READ | _IOC_WRITE, ty, nr, size_of::<T>())
+}
+
+pub const fn _IOC_DIR(cmd: u32) -> u32 {
+    (cmd >> _IOC_DIRSHIFT) & ((1 << _IOC_DIRBITS) - 1)
+}
+
+pub const fn _IOC_TYPE(cmd: u32) -> u8 {
+    (cmd >> _IOC_TYPESHIFT) as u8
+}
+
+pub const fn _IOC_NR(cmd: u32) -> u8 {
+    (cmd >> _IOC_NRSHIFT) as u8
+}
+
+pub const fn _IOC_SIZE(cmd: u32) -> usize {
+    ((cmd >> _IOC_SIZESHIFT) & ((1 << _IOC_SIZEBITS) - 1)) as usize
+}
+
+/// RTC 闹钟，用于 `RTC_WKALM_SET` 与 `RTC_WKALM_RD`
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Pod)]
+pub struct RtcWkAlrm {
+    /// 闹钟是否开启
+    pub enabled: u8,
+    /// 闹钟是否已经触发但尚未处理
+    pub pending: u8,
+    pub __pad: u16,
+    pub time: RtcTime,
+}
+
+pub const RTC_AIE_ON: u32 = _IO(b'p', 0x01);
+pub const RTC_AIE_OFF: u32 = _IO(b'p', 0x02);
+pub const RTC_UIE_ON: u32 = _IO(b'p', 0x03);
+pub const RTC_UIE_OFF: u32 = _IO(b'p', 0x04);
+pub const RTC_PIE_ON: u32 = _IO(b'p', 0x05);
+pub const RTC_PIE_OFF: u32 = _IO(b'p', 0x06);
+pub const RTC_ALM_SET: u32 = _IOW::<RtcTime>(b'p', 0x07);
+pub const RTC_ALM_READ: u32 = _IOR::<RtcTime>(b'p', 0x08);
+pub const RTC_RD_TIME: u32 = _IOR::<RtcTime>(b'p', 0x09);
+pub const RTC_SET_TIME: u32 = _IOW::<RtcTime>(b'p', 0x0a);
+pub const RTC_IRQP_READ: u32 = _IOR::<usize>(b'p', 0x0b);
+pub const RTC_IRQP_SET: u32 = _IOW::<usize>(b'p', 0x0c);
+pub const RTC_WKALM_SET: u32 = _IOW::<RtcWkAlrm>(b'p', 0x0f);
+pub const RTC_WKALM_RD: u32 = _IOR::<RtcWkAlrm>(b'p', 0x10);
+
+/// RTC 设备 (`/dev/rtc`) 的 ioctl
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, IntEnum)]
+pub enum RtcCommand {
+    /// 开启闹钟中断
+    RTC_AIE_ON = 0x7001,
+    /// 关闭闹钟中断
+    RTC_AIE_OFF = 0x7002,
+    /// 开启每秒一次的更新中断
+    RTC_UIE_ON = 0x7003,
+    /// 关闭更新中断
+    RTC_UIE_OFF = 0x7004,
+    /// 开启周期中断
+    RTC_PIE_ON = 0x7005,
+    /// 关闭周期中断
+    RTC_PIE_OFF = 0x7006,
+    /// 设置闹钟时间，参数为 [`RtcTime`]
+    RTC_ALM_SET = 0x40247007,
+    /// 读取闹钟时间，参数为 [`RtcTime`]
+    RTC_ALM_READ = 0x80247008,
+    /// 读取当前时间，参数为 [`RtcTime`]
+    RTC_RD_TIME = 0x80247009,
+    /// 设置当前时间，参数为 [`RtcTime`]
+    RTC_SET_TIME = 0x4024700a,
+    /// 读取周期中断的频率
+    RTC_IRQP_READ = 0x8008700b,
+    /// 设置周期中断的频率
+    RTC_IRQP_SET = 0x4008700c,
+    /// 设置闹钟，参数为 [`RtcWkAlrm`]
+    RTC_WKALM_SET = 0x4028700f,
+    /// 读取闹钟，参数为 [`RtcWkAlrm`]
+    RTC_WKALM_RD = 0x80287010,
+}
+
+pub const BLKROSET: u32 = _IO(0x12, 93);
+pub const BLKROGET: u32 = _IO(0x12, 94);
+pub const BLKGETSIZE: u32 = _IO(0x12, 96);
+pub const BLKFLSBUF: u32 = _IO(0x12, 97);
+pub const BLKSSZGET: u32 = _IO(0x12, 104);
+pub const BLKBSZGET: u32 = _IOR::<usize>(0x12, 112);
+pub const BLKBSZSET: u32 = _IOW::<usize>(0x12, 113);
+pub const BLKGETSIZE64: u32 = _IOR::<usize>(0x12, 114);
+pub const BLKDISCARD: u32 = _IO(0x12, 119);
+pub const BLKPBSZGET: u32 = _IO(0x12, 123);
+
+/// 块设备的 ioctl
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, IntEnum)]
+pub enum BlockCommand {
+    /// 设置只读，参数为 `int`
+    BLKROSET = 0x125d,
+    /// 是否只读，参数为 `int`
+    BLKROGET = 0x125e,
+    /// 设备大小，单位为 512 字节的扇区，参数为 `unsigned long`
+    BLKGETSIZE = 0x1260,
+    /// 写回并丢弃缓存
+    BLKFLSBUF = 0x1261,
+    /// 逻辑扇区大小，参数为 `int`
+    BLKSSZGET = 0x1268,
+    /// 块大小
+    BLKBSZGET = 0x80081270,
+    /// 设置块大小
+    BLKBSZSET = 0x40081271,
+    /// 设备大小，单位为字节，参数为 `u64`
+    BLKGETSIZE64 = 0x80081272,
+    /// 丢弃一段区域，参数为 `u64[2]`：起点与长度
+    BLKDISCARD = 0x1277,
+    /// 物理扇区大小，参数为 `unsigned int`
+    BLKPBSZGET = 0x127b,
+}
+
+pub const FBIOGET_VSCREENINFO: u32 = _IO(b'F', 0x00);
+pub const FBIOPUT_VSCREENINFO: u32 = _IO(b'F', 0x01);
+pub const FBIOGET_FSCREENINFO: u32 = _IO(b'F', 0x02);
+pub const FBIOGETCMAP: u32 = _IO(b'F', 0x04);
+pub const FBIOPUTCMAP: u32 = _IO(b'F', 0x05);
+pub const FBIOPAN_DISPLAY: u32 = _IO(b'F', 0x06);
+pub const FBIOGET_CON2FBMAP: u32 = _IO(b'F', 0x0f);
+pub const FBIOPUT_CON2FBMAP: u32 = _IO(b'F', 0x10);
+pub const FBIOBLANK: u32 = _IO(b'F', 0x11);
+pub const FBIO_WAITFORVSYNC: u32 = _IOW::<u32>(b'F', 0x20);
+
+/// 帧缓冲设备 (`/dev/fbN`) 的 ioctl。
+///
+/// 除 `FBIO_WAITFORVSYNC` 外，这些请求号历史上没有编码方向与大小。
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, IntEnum)]
+pub enum FbCommand {
+    /// 读取可变的屏幕信息 (`fb_var_screeninfo`)
+    FBIOGET_VSCREENINFO = 0x4600,
+    /// 设置可变的屏幕信息
+    FBIOPUT_VSCREENINFO = 0x4601,
+    /// 读取固定的屏幕信息 (`fb_fix_screeninfo`)
+    FBIOGET_FSCREENINFO = 0x4602,
+    /// 读取调色板
+    FBIOGETCMAP = 0x4604,
+    /// 设置调色板
+    FBIOPUTCMAP = 0x4605,
+    /// 平移显示区域
+    FBIOPAN_DISPLAY = 0x4606,
+    /// 读取控制台与帧缓冲的对应关系
+    FBIOGET_CON2FBMAP = 0x460f,
+    /// 设置控制台与帧缓冲的对应关系
+    FBIOPUT_CON2FBMAP = 0x4610,
+    /// 熄屏或亮屏
+    FBIOBLANK = 0x4611,
+    /// 等待垂直同步
+    FBIO_WAITFORVSYNC = 0x40044620,
+}
+
+/// 一个 ioctl 请求
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum IoctlCmd {
+    Tty(TeletypeCommand),
+    Rtc(RtcCommand),
+    Block(BlockCommand),
+    Framebuffer(FbCommand),
+    /// 未知的请求，可以用 `_IOC_*` 拆分
+    Unknown(u32),
+}
+
+impl IoctlCmd {
+    /// 请求号
+    pub const fn raw(&self) -> u32 {
+        match *self {
+            IoctlCmd::Tty(cmd) => cmd as u32,
+            IoctlCmd::Rtc(cmd) => cmd as u32,
+            IoctlCmd::Block(cmd) => cmd as u32,
+            IoctlCmd::Framebuffer(cmd) => cmd as u32,
+            IoctlCmd::Unknown(raw) => raw,
+        }
+    }
+}
+
+impl From<u32> for IoctlCmd {
+    fn from(raw: u32) -> Self {
+        // RTC_RD_TIME 同时存在于 TeletypeCommand 中，优先识别为 RTC 请求
+        if let Ok(cmd) = RtcCommand::try_from(raw) {
+            IoctlCmd::Rtc(cmd)
+        } else if let Ok(cmd) = TeletypeCommand::try_from(raw) {
+            IoctlCmd::Tty(cmd)
+        } else if let Ok(cmd) = BlockCommand::try_from(raw) {
+            IoctlCmd::Block(cmd)
+        } else if let Ok(cmd) = FbCommand::try_from(raw) {
+            IoctlCmd::Framebuffer(cmd)
+        } else {
+            IoctlCmd::Unknown(raw)
+        }
+    }
+}
+
+impl From<IoctlCmd> for u32 {
+    fn from(cmd: IoctlCmd) -> Self {
+        cmd.raw()
+    }
+}
+
+const _: () = assert!(core::mem::size_of::<RtcWkAlrm>() == 40);
+const _: () = assert!(RTC_RD_TIME == 0x80247009);
+const _: () = assert!(RTC_SET_TIME == 0x4024700a);
+const _: () = assert!(BLKGETSIZE64 == 0x80081272);
+const _: () = assert!(BLKSSZGET == 0x1268);
+const _: () = assert!(FBIOBLANK == 0x4611);
+const _: () = assert!(TeletypeCommand::TIOCGPTN as u32 == _IOR::<u32>(b'T', 0x30));
+const _: () = assert!(TeletypeCommand::TIOCSPTLCK as u32 == _IOW::<i32>(b'T', 0x31));
+const _: () = assert!(
+    _IOC_DIR(RTC_RD_TIME) == _IOC_READ
+        && _IOC_TYPE(RTC_RD_TIME) == b'p'
+        && _IOC_NR(RTC_RD_TIME) == 0x09
+        && _IOC_SIZE(RTC_RD_TIME) == 36
+);
+
+// 枚举中的请求号与由编码函数得到的常量一致
+const _: () = {
+    assert!(RtcCommand::RTC_AIE_ON as u32 == RTC_AIE_ON);
+    assert!(RtcCommand::RTC_AIE_OFF as u32 == RTC_AIE_OFF);
+    assert!(RtcCommand::RTC_UIE_ON as u32 == RTC_UIE_ON);
+    assert!(RtcCommand::RTC_UIE_OFF as u32 == RTC_UIE_OFF);
+    assert!(RtcCommand::RTC_PIE_ON as u32 == RTC_PIE_ON);
+    assert!(RtcCommand::RTC_PIE_OFF as u32 == RTC_PIE_OFF);
+    assert!(RtcCommand::RTC_ALM_SET as u32 == RTC_ALM_SET);
+    assert!(RtcCommand::RTC_ALM_READ as u32 == RTC_ALM_READ);
+    assert!(RtcCommand::RTC_RD_TIME as u32 == RTC_RD_TIME);
+    assert!(RtcCommand::RTC_SET_TIME as u32 == RTC_SET_TIME);
+    assert!(RtcCommand::RTC_IRQP_READ as u32 == RTC_IRQP_READ);
+    assert!(RtcCommand::RTC_IRQP_SET as u32 == RTC_IRQP_SET);
+    assert!(RtcCommand::RTC_WKALM_SET as u32 == RTC_WKALM_SET);
+    assert!(RtcCommand::RTC_WKALM_RD as u32 == RTC_WKALM_RD);
+};
+const _: () = {
+    assert!(BlockCommand::BLKROSET as u32 == BLKROSET);
+    assert!(BlockCommand::BLKROGET as u32 == BLKROGET);
+    assert!(BlockCommand::BLKGETSIZE as u32 == BLKGETSIZE);
+    assert!(BlockCommand::BLKFLSBUF as u32 == BLKFLSBUF);
+    assert!(BlockCommand::BLKSSZGET as u32 == BLKSSZGET);
+    assert!(BlockCommand::BLKBSZGET as u32 == BLKBSZGET);
+    assert!(BlockCommand::BLKBSZSET as u32 == BLKBSZSET);
+    assert!(BlockCommand::BLKGETSIZE64 as u32 == BLKGETSIZE64);
+    assert!(BlockCommand::BLKDISCARD as u32 == BLKDISCARD);
+    assert!(BlockCommand::BLKPBSZGET as u32 == BLKPBSZGET);
+};
+const _: () = {
+    assert!(FbCommand::FBIOGET_VSCREENINFO as u32 == FBIOGET_VSCREENINFO);
+    assert!(FbCommand::FBIOPUT_VSCREENINFO as u32 == FBIOPUT_VSCREENINFO);
+    assert!(FbCommand::FBIOGET_FSCREENINFO as u32 == FBIOGET_FSCREENINFO);
+    assert!(FbCommand::FBIOGETCMAP as u32 == FBIOGETCMAP);
+    assert!(FbCommand::FBIOPUTCMAP as u32 == FBIOPUTCMAP);
+    assert!(FbCommand::FBIOPAN_DISPLAY as u32 == FBIOPAN_DISPLAY);
+    assert!(FbCommand::FBIOGET_CON2FBMAP as u32 == FBIOGET_CON2FBMAP);
+    assert!(FbCommand::FBIOPUT_CON2FBMAP as u32 == FBIOPUT_CON2FBMAP);
+    assert!(FbCommand::FBIOBLANK as u32 == FBIOBLANK);
+    assert!(FbCommand::FBIO_WAITFORVSYNC as u32 == FBIO_WAITFORVSYNC);
+};
+const _: () = assert!(TeletypeCommand::RTC_RD_TIME as u32 == RTC_RD_TIME);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_requests() {
+        assert_eq!(
+            IoctlCmd::from(0x80247009),
+            IoctlCmd::Rtc(RtcCommand::RTC_RD_TIME)
+        );
+        assert_eq!(
+            IoctlCmd::from(0x5401),
+            IoctlCmd::Tty(TeletypeCommand::TCGETS)
+        );
+        assert_eq!(
+            IoctlCmd::from(0x80081272),
+            IoctlCmd::Block(BlockCommand::BLKGETSIZE64)
+        );
+        assert_eq!(
+            IoctlCmd::from(0x4611),
+            IoctlCmd::Framebuffer(FbCommand::FBIOBLANK)
+        );
+        assert_eq!(IoctlCmd::from(0), IoctlCmd::Unknown(0));
+        for raw in [RTC_SET_TIME, 0x5413, BLKSSZGET, FBIO_WAITFORVSYNC, 0xdead] {
+            assert_eq!(IoctlCmd::from(raw).raw(), raw);
+        }
+    }
+
+    #[test]
+    fn encode_and_decode() {
+        let cmd = _IOWR::<[u8; 16]>(b'x', 3);
+        assert_eq!(cmd, 0xc0107803);
+        assert_eq!(_IOC_DIR(cmd), _IOC_READ | _IOC_WRITE);
+        assert_eq!(
+            (_IOC_TYPE(cmd), _IOC_NR(cmd), _IOC_SIZE(cmd)),
+            (b'x', 3, 16)
+        );
+        assert_eq!(_IO(b'T', 0x41), 0x5441);
+    }
+}
@@ -4,14 +4,19 @@
 extern crate alloc;
 
 pub use consts::*;
+pub use syscall::*;
 
 pub mod aux;
 mod consts;
 pub mod epoll;
 pub mod io;
+pub mod ioctl;
 pub mod ipc;
+pub mod lock;
 pub mod net;
 pub mod signal;
 pub mod sys;
+mod syscall;
 pub mod task;
 pub mod time;
+pub mod tty;
@@ -0,0 +1,469 @@
+//! POSIX 记录锁 (`fcntl` 的 `F_SETLK`/`F_SETLKW`/`F_GETLK` 以及 OFD 锁)。
+//!
+//! 每个 inode 持有一个 [`LockTable`]，所有的锁共用一个 [`LockWaiters`]，
+//! 用于在 `F_SETLKW` 等待前检查死锁。
+
+use alloc::vec::Vec;
+
+use crate::{
+    io::{Flock, FlockType, Whence},
+    LinuxErrno,
+};
+
+/// 锁区间的结束位置，表示一直锁到文件末尾之后
+pub const LOCK_EOF: u64 = u64::MAX;
+
+/// 锁的持有者
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LockOwner {
+    /// 传统的 POSIX 锁属于进程，进程关闭该文件的任一 fd 时释放
+    Process(u32),
+    /// OFD 锁属于打开的文件描述，最后一个引用它的 fd 关闭时释放。
+    /// 值由内核分配，只要求在打开的文件描述之间唯一
+    Ofd(usize),
+}
+
+/// 一把记录锁，锁住 `[start, end)`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RecordLock {
+    pub owner: LockOwner,
+    /// [`FlockType::F_RDLCK`] 或 [`FlockType::F_WRLCK`]，
+    /// 作为请求时也可以是 [`FlockType::F_UNLCK`]
+    pub ty: FlockType,
+    pub start: u64,
+    /// 不包含在区间内，[`LOCK_EOF`] 表示直到文件末尾之后
+    pub end: u64,
+}
+
+impl RecordLock {
+    /// 由用户传入的 [`Flock`] 构造锁请求。
+    ///
+    /// `cur` 与 `size` 为文件当前的偏移与大小，用于解析 `l_whence`。
+    /// OFD 锁要求 `l_pid` 为 0。
+    pub fn from_flock(
+        flock: &Flock,
+        owner: LockOwner,
+        cur: u64,
+        size: u64,
+    ) -> Result<Self, LinuxErrno> {
+        if matches!(owner, LockOwner::Ofd(_)) && flock.l_pid != 0 {
+            return Err(LinuxErrno::EINVAL);
+        }
+        let (start, end) = flock.range(cur, size)?;
+        Ok(Self {
+            owner,
+            ty: flock.lock_type()?,
+            start,
+            end,
+        })
+    }
+
+    /// 转换为 `F_GETLK` 返回给用户的 [`Flock`]
+    pub fn to_flock(&self) -> Flock {
+        Flock {
+            l_type: self.ty as i16,
+            l_whence: Whence::SeekSet as i16,
+            l_start: self.start as i64,
+            l_len: match self.end {
+                LOCK_EOF => 0,
+                end => (end - self.start) as i64,
+            },
+            l_pid: match self.owner {
+                LockOwner::Process(pid) => pid as i32,
+                LockOwner::Ofd(_) => -1,
+            },
+            ..Default::default()
+        }
+    }
+
+    fn overlaps(&self, start: u64, end: u64) -> bool {
+        self.start < end && start < self.end
+    }
+
+    /// 两把锁是否冲突：持有者不同、区间重叠且至少有一把是写锁
+    pub fn conflicts_with(&self, other: &RecordLock) -> bool {
+        self.owner != other.owner
+            && self.overlaps(other.start, other.end)
+            && (self.ty == FlockType::F_WRLCK || other.ty == FlockType::F_WRLCK)
+    }
+}
+
+impl Flock {
+    /// 计算锁住的区间 `[start, end)`，`cur` 与 `size` 用于解析 `l_whence`。
+    ///
+    /// 起点为负时返回 `EINVAL`，计算溢出时返回 `EOVERFLOW`。
+    pub fn range(&self, cur: u64, size: u64) -> Result<(u64, u64), LinuxErrno> {
+        let base = match Whence::try_from(self.l_whence as usize) {
+            Ok(Whence::SeekSet) => 0,
+            Ok(Whence::SeekCur) => cur,
+            Ok(Whence::SeekEnd) => size,
+            _ => return Err(LinuxErrno::EINVAL),
+        };
+        let base = i64::try_from(base).map_err(|_| LinuxErrno::EOVERFLOW)?;
+        let start = base
+            .checked_add(self.l_start)
+            .ok_or(LinuxErrno::EOVERFLOW)?;
+        let (start, end) = match self.l_len {
+            0 => (start, None),
+            len if len > 0 => (
+                start,
+                Some(start.checked_add(len).ok_or(LinuxErrno::EOVERFLOW)?),
+            ),
+            len => (
+                start.checked_add(len).ok_or(LinuxErrno::EOVERFLOW)?,
+                Some(start),
+            ),
+        };
+        if start < 0 {
+            return Err(LinuxErrno::EINVAL);
+        }
+        Ok((start as u64, end.map_or(LOCK_EOF, |end| end as u64)))
+    }
+}
+
+/// `F_SETLKW` 的结果
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LockWait {
+    /// 已经加锁
+    Acquired,
+    /// 与该锁冲突，需要在 [`LockWaiters`] 中登记后等待，被唤醒后重试
+    Blocked(RecordLock),
+}
+
+/// 一个 inode 上的所有记录锁
+#[derive(Debug, Clone, Default)]
+pub struct LockTable {
+    /// 按起点排序，同一持有者的锁互不重叠
+    locks: Vec<RecordLock>,
+}
+
+impl LockTable {
+    pub const fn new() -> Self {
+        Self { locks: Vec::new() }
+    }
+
+    /// 当前持有的所有锁
+    pub fn locks(&self) -> &[RecordLock] {
+        &self.locks
+    }
+
+    /// `F_GETLK`：返回与请求冲突的第一把锁
+    pub fn test(&self, request: &RecordLock) -> Option<RecordLock> {
+        if request.ty == FlockType::F_UNLCK {
+            return None;
+        }
+        self.locks
+            .iter()
+            .find(|lock| lock.conflicts_with(request))
+            .copied()
+    }
+
+    /// `F_SETLK`：加锁、改变锁的类型或解锁，冲突时返回 `EAGAIN`
+    pub fn set(&mut self, request: RecordLock) -> Result<(), LinuxErrno> {
+        if self.test(&request).is_some() {
+            return Err(LinuxErrno::EAGAIN);
+        }
+        self.apply(request);
+        Ok(())
+    }
+
+    /// `F_SETLKW`：与 [`LockTable::set`] 相同，但冲突时返回需要等待的锁。
+    ///
+    /// 若等待会形成死锁则返回 `EDEADLK`。与 Linux 一致，只检查进程持有的锁。
+    pub fn set_wait(
+        &mut self,
+        request: RecordLock,
+        waiters: &LockWaiters,
+    ) -> Result<LockWait, LinuxErrno> {
+        match self.test(&request) {
+            Some(blocker) => {
+                if waiters.would_deadlock(request.owner, blocker.owner) {
+                    Err(LinuxErrno::EDEADLK)
+                } else {
+                    Ok(LockWait::Blocked(blocker))
+                }
+            }
+            None => {
+                self.apply(request);
+                Ok(LockWait::Acquired)
+            }
+        }
+    }
+
+    /// 释放 `owner` 在该文件上的所有锁
+    pub fn release(&mut self, owner: LockOwner) {
+        self.locks.retain(|lock| lock.owner != owner);
+    }
+
+    /// 在不检查冲突的情况下应用请求：切分原有的锁，然后合并相邻的同类锁
+    fn apply(&mut self, request: RecordLock) {
+        let mut kept = Vec::with_capacity(self.locks.len() + 2);
+        for lock in self.locks.drain(..) {
+            if lock.owner != request.owner || !lock.overlaps(request.start, request.end) {
+                kept.push(lock);
+                continue;
+            }
+            if lock.start < request.start {
+                kept.push(RecordLock {
+                    end: request.start,
+                    ..lock
+                });
+            }
+            if lock.end > request.end {
+                kept.push(RecordLock {
+                    start: request.end,
+                    ..lock
+                });
+            }
+        }
+        self.locks = kept;
+        if request.ty == FlockType::F_UNLCK {
+            self.locks.sort_by_key(|lock| lock.start);
+            return;
+        }
+        let mut merged = request;
+        self.locks.retain(|lock| {
+            let adjacent = lock.owner == merged.owner
+                && lock.ty == merged.ty
+                && lock.start <= merged.end
+                && merged.start <= lock.end;
+            if adjacent {
+                merged.start = merged.start.min(lock.start);
+                merged.end = merged.end.max(lock.end);
+            }
+            !adjacent
+        });
+        self.locks.push(merged);
+        self.locks.sort_by_key(|lock| lock.start);
+    }
+}
+
+/// 正在 `F_SETLKW` 中等待的持有者，即等待图，用于死锁检测
+#[derive(Debug, Clone, Default)]
+pub struct LockWaiters {
+    /// (等待者, 持有冲突锁的一方)
+    edges: Vec<(LockOwner, LockOwner)>,
+}
+
+impl LockWaiters {
+    pub const fn new() -> Self {
+        Self { edges: Vec::new() }
+    }
+
+    /// 记录 `waiter` 开始等待 `blocker`
+    pub fn add(&mut self, waiter: LockOwner, blocker: LockOwner) {
+        self.remove(waiter);
+        self.edges.push((waiter, blocker));
+    }
+
+    /// `waiter` 结束等待，无论是否加锁成功
+    pub fn remove(&mut self, waiter: LockOwner) {
+        self.edges.retain(|&(owner, _)| owner != waiter);
+    }
+
+    /// `waiter` 等待 `blocker` 是否会形成环
+    pub fn would_deadlock(&self, waiter: LockOwner, blocker: LockOwner) -> bool {
+        if !matches!(waiter, LockOwner::Process(_)) {
+            return false;
+        }
+        let mut current = blocker;
+        // 每个持有者同时只会等待一把锁，最多走 edges.len() 步
+        for _ in 0..=self.edges.len() {
+            if current == waiter {
+                return true;
+            }
+            match self.edges.iter().find(|&&(owner, _)| owner == current) {
+                Some(&(_, next)) => current = next,
+                None => return false,
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const P1: LockOwner = LockOwner::Process(1);
+    const P2: LockOwner = LockOwner::Process(2);
+
+    fn lock(owner: LockOwner, ty: FlockType, start: u64, end: u64) -> RecordLock {
+        RecordLock {
+            owner,
+            ty,
+            start,
+            end,
+        }
+    }
+
+    fn flock(whence: Whence, start: i64, len: i64) -> Flock {
+        Flock {
+            l_type: FlockType::F_WRLCK as i16,
+            l_whence: whence as i16,
+            l_start: start,
+            l_len: len,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unlock_splits_lock() {
+        let mut table = LockTable::new();
+        table.set(lock(P1, FlockType::F_WRLCK, 0, 100)).unwrap();
+        table.set(lock(P1, FlockType::F_UNLCK, 40, 60)).unwrap();
+        assert_eq!(
+            table.locks(),
+            &[
+                lock(P1, FlockType::F_WRLCK, 0, 40),
+                lock(P1, FlockType::F_WRLCK, 60, 100)
+            ]
+        );
+        // 被解锁的区间可以被其他进程加锁
+        table.set(lock(P2, FlockType::F_WRLCK, 40, 60)).unwrap();
+        assert_eq!(table.locks().len(), 3);
+    }
+
+    #[test]
+    fn adjacent_locks_merge() {
+        let mut table = LockTable::new();
+        table.set(lock(P1, FlockType::F_RDLCK, 0, 10)).unwrap();
+        table.set(lock(P1, FlockType::F_RDLCK, 20, 30)).unwrap();
+        table.set(lock(P1, FlockType::F_RDLCK, 10, 20)).unwrap();
+        assert_eq!(table.locks(), &[lock(P1, FlockType::F_RDLCK, 0, 30)]);
+        // 不同类型的相邻锁不合并
+        table.set(lock(P1, FlockType::F_WRLCK, 30, 40)).unwrap();
+        assert_eq!(table.locks().len(), 2);
+    }
+
+    #[test]
+    fn upgrade_read_to_write() {
+        let mut table = LockTable::new();
+        table.set(lock(P1, FlockType::F_RDLCK, 0, 100)).unwrap();
+        table.set(lock(P1, FlockType::F_WRLCK, 50, 60)).unwrap();
+        assert_eq!(
+            table.locks(),
+            &[
+                lock(P1, FlockType::F_RDLCK, 0, 50),
+                lock(P1, FlockType::F_WRLCK, 50, 60),
+                lock(P1, FlockType::F_RDLCK, 60, 100)
+            ]
+        );
+        assert!(table.test(&lock(P2, FlockType::F_RDLCK, 10, 20)).is_none());
+        assert_eq!(
+            table.test(&lock(P2, FlockType::F_RDLCK, 55, 56)),
+            Some(lock(P1, FlockType::F_WRLCK, 50, 60))
+        );
+        // 其他进程持有读锁时不能升级
+        table.set(lock(P2, FlockType::F_RDLCK, 0, 10)).unwrap();
+        assert_eq!(
+            table.set(lock(P1, FlockType::F_WRLCK, 0, 100)),
+            Err(LinuxErrno::EAGAIN)
+        );
+        table.release(P2);
+        table.set(lock(P1, FlockType::F_WRLCK, 0, 100)).unwrap();
+        assert_eq!(table.locks(), &[lock(P1, FlockType::F_WRLCK, 0, 100)]);
+    }
+
+    #[test]
+    fn posix_and_ofd_locks_conflict() {
+        let ofd = LockOwner::Ofd(0x1000);
+        let mut table = LockTable::new();
+        table.set(lock(P1, FlockType::F_WRLCK, 0, 10)).unwrap();
+        let request = lock(ofd, FlockType::F_RDLCK, 5, 6);
+        assert_eq!(table.set(request), Err(LinuxErrno::EAGAIN));
+        table.release(P1);
+        table.set(request).unwrap();
+        let conflict = table
+            .test(&lock(P1, FlockType::F_WRLCK, 0, LOCK_EOF))
+            .unwrap();
+        let flock = conflict.to_flock();
+        assert_eq!((flock.l_start, flock.l_len, flock.l_pid), (5, 1, -1));
+        // OFD 锁要求 l_pid 为 0
+        let with_pid = Flock { l_pid: 1, ..flock };
+        assert_eq!(
+            RecordLock::from_flock(&with_pid, ofd, 0, 0),
+            Err(LinuxErrno::EINVAL)
+        );
+    }
+
+    #[test]
+    fn two_process_deadlock() {
+        let mut table = LockTable::new();
+        let mut waiters = LockWaiters::new();
+        table.set(lock(P1, FlockType::F_WRLCK, 0, 10)).unwrap();
+        table.set(lock(P2, FlockType::F_WRLCK, 10, 20)).unwrap();
+        let blocked = table
+            .set_wait(lock(P1, FlockType::F_WRLCK, 10, 20), &waiters)
+            .unwrap();
+        assert_eq!(
+            blocked,
+            LockWait::Blocked(lock(P2, FlockType::F_WRLCK, 10, 20))
+        );
+        waiters.add(P1, P2);
+        assert_eq!(
+            table.set_wait(lock(P2, FlockType::F_WRLCK, 0, 10), &waiters),
+            Err(LinuxErrno::EDEADLK)
+        );
+        // OFD 锁不做死锁检测
+        assert!(!waiters.would_deadlock(LockOwner::Ofd(1), P1));
+        // P1 放弃等待后不再构成环
+        waiters.remove(P1);
+        assert!(matches!(
+            table.set_wait(lock(P2, FlockType::F_WRLCK, 0, 10), &waiters),
+            Ok(LockWait::Blocked(_))
+        ));
+        table.release(P1);
+        assert_eq!(
+            table.set_wait(lock(P2, FlockType::F_WRLCK, 0, 10), &waiters),
+            Ok(LockWait::Acquired)
+        );
+    }
+
+    #[test]
+    fn flock_range() {
+        assert_eq!(flock(Whence::SeekSet, 10, 5).range(0, 0), Ok((10, 15)));
+        assert_eq!(
+            flock(Whence::SeekCur, 10, 0).range(100, 0),
+            Ok((110, LOCK_EOF))
+        );
+        assert_eq!(
+            flock(Whence::SeekEnd, -10, 0).range(0, 1000),
+            Ok((990, LOCK_EOF))
+        );
+        // 负的长度锁住起点之前的区域
+        assert_eq!(flock(Whence::SeekSet, 100, -10).range(0, 0), Ok((90, 100)));
+        assert_eq!(
+            flock(Whence::SeekSet, 5, -10).range(0, 0),
+            Err(LinuxErrno::EINVAL)
+        );
+        assert_eq!(
+            flock(Whence::SeekEnd, -1, 0).range(0, 0),
+            Err(LinuxErrno::EINVAL)
+        );
+        assert_eq!(
+            flock(Whence::SeekData, 0, 0).range(0, 0),
+            Err(LinuxErrno::EINVAL)
+        );
+    }
+
+    #[test]
+    fn flock_range_overflow() {
+        assert_eq!(
+            flock(Whence::SeekCur, 0, 0).range(u64::MAX, 0),
+            Err(LinuxErrno::EOVERFLOW)
+        );
+        assert_eq!(
+            flock(Whence::SeekCur, i64::MAX, 0).range(1, 0),
+            Err(LinuxErrno::EOVERFLOW)
+        );
+        assert_eq!(
+            flock(Whence::SeekSet, 1, i64::MAX).range(0, 0),
+            Err(LinuxErrno::EOVERFLOW)
+        );
+        assert_eq!(
+            flock(Whence::SeekSet, i64::MIN + 1, -2).range(0, 0),
+            Err(LinuxErrno::EOVERFLOW)
+        );
+    }
+}
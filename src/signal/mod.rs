@@ -33,6 +33,12 @@ pub struct SignalHandlers {
     actions: [Option<SigAction>; SIGSET_SIZE_IN_BIT],
 }
 
+impl Default for SignalHandlers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SignalHandlers {
     /// 新建一个信号模块
     pub fn new() -> Self {
@@ -49,7 +55,7 @@ impl SignalHandlers {
     }
     /// 获取某个信号对应的 SigAction。
     /// 因为 signum 的范围是 \[1,64\]，所以要 -1
-    pub fn get_action<'a>(&self, signum: usize, action_pos: &mut SigAction) {
+    pub fn get_action(&self, signum: usize, action_pos: &mut SigAction) {
         if let Some(action) = self.actions[signum - 1] {
             *action_pos = action;
         }
@@ -83,6 +89,12 @@ pub struct SignalReceivers {
     pub sig_received: SimpleBitSet,
 }
 
+impl Default for SignalReceivers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SignalReceivers {
     /// 新建一个处理模块
     pub fn new() -> Self {
@@ -170,11 +182,11 @@ impl SimpleBitSet {
     }
 }
 
-impl Into<Vec<SignalNumber>> for SimpleBitSet {
-    fn into(self) -> Vec<SignalNumber> {
+impl From<SimpleBitSet> for Vec<SignalNumber> {
+    fn from(set: SimpleBitSet) -> Self {
         let mut ans = Vec::new();
         for i in 0..64 {
-            if self.0 & (1 << i) != 0 {
+            if set.0 & (1 << i) != 0 {
                 ans.push(SignalNumber::try_from(i).unwrap());
             }
         }
@@ -205,12 +217,14 @@ impl core::ops::SubAssign for SimpleBitSet {
 impl core::ops::Add for SimpleBitSet {
     type Output = Self;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn add(self, rhs: Self) -> Self::Output {
         Self(self.0 | rhs.0)
     }
 }
 
 impl core::ops::AddAssign for SimpleBitSet {
+    #[allow(clippy::suspicious_op_assign_impl)]
     fn add_assign(&mut self, rhs: Self) {
         self.0 |= rhs.0;
     }
@@ -113,8 +113,8 @@ pub enum PrctlOp {
     PR_SET_DUMPABLE = 4,
     /// Get/set unaligned access control bits (if meaningful)
     ///
-    /// - PR_UNALIGN_NOPRINT = 1:	 silently fix up unaligned user accesses
-    /// - PR_UNALIGN_SIGBUS = 2:	 generate SIGBUS on unaligned user access
+    /// - PR_UNALIGN_NOPRINT = 1: silently fix up unaligned user accesses
+    /// - PR_UNALIGN_SIGBUS = 2: generate SIGBUS on unaligned user access
     PR_GET_UNALIGN = 5,
     PR_SET_UNALIGN = 6,
     /// Get/set whether or not to drop capabilities on setuid() away from
@@ -123,22 +123,22 @@ pub enum PrctlOp {
     PR_SET_KEEPCAPS = 8,
     ///  Get/set floating-point emulation control bits (if meaningful)
     ///
-    /// -  PR_FPEMU_NOPRINT	=1	: silently emulate fp operations accesses
-    /// -  PR_FPEMU_SIGFPE =2	: don't emulate fp operations, send SIGFPE instead
+    /// -  PR_FPEMU_NOPRINT =1 : silently emulate fp operations accesses
+    /// -  PR_FPEMU_SIGFPE =2 : don't emulate fp operations, send SIGFPE instead
     PR_GET_FPEMU = 9,
     PR_SET_FPEMU = 10,
     /// Get/set floating-point exception mode (if meaningful)
     ///
     /// - PR_FP_EXC_SW_ENABLE = 0x80 : Use FPEXC for FP exception enables
-    /// - PR_FP_EXC_DIV = 	0x010000 : floating point divide by zero
-    /// - PR_FP_EXC_OVF	= 0x020000	: floating point overflow
-    /// - PR_FP_EXC_UND	= 0x040000	: floating point underflow
-    /// - PR_FP_EXC_RES	= 0x080000	: floating point inexact result
-    /// - PR_FP_EXC_INV	= 0x100000	: floating point invalid operation
-    /// - PR_FP_EXC_DISABLED	= 0	:FP exceptions disabled
-    /// - PR_FP_EXC_NONRECOV	= 1	: async non-recoverable exc. mode
-    /// - PR_FP_EXC_ASYNC	= 2	: async recoverable exception mode
-    /// - PR_FP_EXC_PRECISE	= 3	: precise exception mode
+    /// - PR_FP_EXC_DIV = 0x010000 : floating point divide by zero
+    /// - PR_FP_EXC_OVF = 0x020000 : floating point overflow
+    /// - PR_FP_EXC_UND = 0x040000 : floating point underflow
+    /// - PR_FP_EXC_RES = 0x080000 : floating point inexact result
+    /// - PR_FP_EXC_INV = 0x100000 : floating point invalid operation
+    /// - PR_FP_EXC_DISABLED = 0 :FP exceptions disabled
+    /// - PR_FP_EXC_NONRECOV = 1 : async non-recoverable exc. mode
+    /// - PR_FP_EXC_ASYNC = 2 : async recoverable exception mode
+    /// - PR_FP_EXC_PRECISE = 3 : precise exception mode
     PR_GET_FPEXC = 11,
     PR_SET_FPEXC = 12,
 
@@ -0,0 +1,346 @@
+//! System call numbers from `include/uapi/asm-generic/unistd.h`.
+//!
+//! riscv64, aarch64 and loongarch64 all use this numbering. The few entries
+//! that only exist on some of them are gated by their `arch-*` feature; the
+//! riscv-only ones are also enabled by the default `riscv` feature as long as
+//! no other `arch-*` feature is selected.
+
+syscall_table! {
+    0 => SYSCALL_IO_SETUP, IoSetup, "io_setup";
+    1 => SYSCALL_IO_DESTROY, IoDestroy, "io_destroy";
+    2 => SYSCALL_IO_SUBMIT, IoSubmit, "io_submit";
+    3 => SYSCALL_IO_CANCEL, IoCancel, "io_cancel";
+    4 => SYSCALL_IO_GETEVENTS, IoGetevents, "io_getevents";
+    5 => SYSCALL_SETXATTR, Setxattr, "setxattr";
+    6 => SYSCALL_LSETXATTR, Lsetxattr, "lsetxattr";
+    7 => SYSCALL_FSETXATTR, Fsetxattr, "fsetxattr";
+    8 => SYSCALL_GETXATTR, Getxattr, "getxattr";
+    9 => SYSCALL_LGETXATTR, Lgetxattr, "lgetxattr";
+    10 => SYSCALL_FGETXATTR, Fgetxattr, "fgetxattr";
+    11 => SYSCALL_LISTXATTR, Listxattr, "listxattr";
+    12 => SYSCALL_LLISTXATTR, Llistxattr, "llistxattr";
+    13 => SYSCALL_FLISTXATTR, Flistxattr, "flistxattr";
+    14 => SYSCALL_REMOVEXATTR, Removexattr, "removexattr";
+    15 => SYSCALL_LREMOVEXATTR, Lremovexattr, "lremovexattr";
+    16 => SYSCALL_FREMOVEXATTR, Fremovexattr, "fremovexattr";
+    17 => SYSCALL_GETCWD, Getcwd, "getcwd";
+    18 => SYSCALL_LOOKUP_DCOOKIE, LookupDcookie, "lookup_dcookie";
+    19 => SYSCALL_EVENTFD2, Eventfd2, "eventfd2";
+    20 => SYSCALL_EPOLL_CREATE1, EpollCreate1, "epoll_create1";
+    21 => SYSCALL_EPOLL_CTL, EpollCtl, "epoll_ctl";
+    22 => SYSCALL_EPOLL_PWAIT, EpollPwait, "epoll_pwait";
+    23 => SYSCALL_DUP, Dup, "dup";
+    24 => SYSCALL_DUP3, Dup3, "dup3";
+    25 => SYSCALL_FCNTL, Fcntl, "fcntl";
+    26 => SYSCALL_INOTIFY_INIT1, InotifyInit1, "inotify_init1";
+    27 => SYSCALL_INOTIFY_ADD_WATCH, InotifyAddWatch, "inotify_add_watch";
+    28 => SYSCALL_INOTIFY_RM_WATCH, InotifyRmWatch, "inotify_rm_watch";
+    29 => SYSCALL_IOCTL, Ioctl, "ioctl";
+    30 => SYSCALL_IOPRIO_SET, IoprioSet, "ioprio_set";
+    31 => SYSCALL_IOPRIO_GET, IoprioGet, "ioprio_get";
+    32 => SYSCALL_FLOCK, Flock, "flock";
+    33 => SYSCALL_MKNODAT, Mknodat, "mknodat";
+    34 => SYSCALL_MKDIRAT, Mkdirat, "mkdirat";
+    35 => SYSCALL_UNLINKAT, Unlinkat, "unlinkat";
+    36 => SYSCALL_SYMLINKAT, Symlinkat, "symlinkat";
+    37 => SYSCALL_LINKAT, Linkat, "linkat";
+    #[cfg(feature = "arch-aarch64")]
+    38 => SYSCALL_RENAMEAT, Renameat, "renameat";
+    39 => SYSCALL_UMOUNT2, Umount2, "umount2";
+    40 => SYSCALL_MOUNT, Mount, "mount";
+    41 => SYSCALL_PIVOT_ROOT, PivotRoot, "pivot_root";
+    43 => SYSCALL_STATFS, Statfs, "statfs";
+    44 => SYSCALL_FSTATFS, Fstatfs, "fstatfs";
+    45 => SYSCALL_TRUNCATE, Truncate, "truncate";
+    46 => SYSCALL_FTRUNCATE, Ftruncate, "ftruncate";
+    47 => SYSCALL_FALLOCATE, Fallocate, "fallocate";
+    48 => SYSCALL_FACCESSAT, Faccessat, "faccessat";
+    49 => SYSCALL_CHDIR, Chdir, "chdir";
+    50 => SYSCALL_FCHDIR, Fchdir, "fchdir";
+    51 => SYSCALL_CHROOT, Chroot, "chroot";
+    52 => SYSCALL_FCHMOD, Fchmod, "fchmod";
+    53 => SYSCALL_FCHMODAT, Fchmodat, "fchmodat";
+    54 => SYSCALL_FCHOWNAT, Fchownat, "fchownat";
+    55 => SYSCALL_FCHOWN, Fchown, "fchown";
+    56 => SYSCALL_OPENAT, Openat, "openat";
+    57 => SYSCALL_CLOSE, Close, "close";
+    58 => SYSCALL_VHANGUP, Vhangup, "vhangup";
+    59 => SYSCALL_PIPE2, Pipe2, "pipe2";
+    60 => SYSCALL_QUOTACTL, Quotactl, "quotactl";
+    61 => SYSCALL_GETDENTS64, Getdents64, "getdents64";
+    62 => SYSCALL_LSEEK, Lseek, "lseek";
+    63 => SYSCALL_READ, Read, "read";
+    64 => SYSCALL_WRITE, Write, "write";
+    65 => SYSCALL_READV, Readv, "readv";
+    66 => SYSCALL_WRITEV, Writev, "writev";
+    67 => SYSCALL_PREAD, Pread64, "pread64";
+    68 => SYSCALL_PWRITE, Pwrite64, "pwrite64";
+    69 => SYSCALL_PREADV, Preadv, "preadv";
+    70 => SYSCALL_PWRITEV, Pwritev, "pwritev";
+    71 => SYSCALL_SENDFILE, Sendfile, "sendfile";
+    72 => SYSCALL_PSELECT6, Pselect6, "pselect6";
+    73 => SYSCALL_PPOLL, Ppoll, "ppoll";
+    74 => SYSCALL_SIGNALFD4, Signalfd4, "signalfd4";
+    75 => SYSCALL_VMSPLICE, Vmsplice, "vmsplice";
+    76 => SYSCALL_SPLICE, Splice, "splice";
+    77 => SYSCALL_TEE, Tee, "tee";
+    78 => SYSCALL_READLINKAT, Readlinkat, "readlinkat";
+    79 => SYSCALL_FSTATAT, Newfstatat, "newfstatat";
+    80 => SYSCALL_FSTAT, Fstat, "fstat";
+    81 => SYSCALL_SYNC, Sync, "sync";
+    82 => SYSCALL_FSYNC, Fsync, "fsync";
+    83 => SYSCALL_FDATASYNC, Fdatasync, "fdatasync";
+    84 => SYSCALL_SYNC_FILE_RANGE, SyncFileRange, "sync_file_range";
+    85 => SYSCALL_TIMERFD_CREATE, TimerfdCreate, "timerfd_create";
+    86 => SYSCALL_TIMERFD_SETTIME, TimerfdSettime, "timerfd_settime";
+    87 => SYSCALL_TIMERFD_GETTIME, TimerfdGettime, "timerfd_gettime";
+    88 => SYSCALL_UTIMENSAT, Utimensat, "utimensat";
+    89 => SYSCALL_ACCT, Acct, "acct";
+    90 => SYSCALL_CAPGET, Capget, "capget";
+    91 => SYSCALL_CAPSET, Capset, "capset";
+    92 => SYSCALL_PERSONALITY, Personality, "personality";
+    93 => SYSCALL_EXIT, Exit, "exit";
+    94 => SYSCALL_EXIT_GROUP, ExitGroup, "exit_group";
+    95 => SYSCALL_WAITID, Waitid, "waitid";
+    96 => SYSCALL_SET_TID_ADDRESS, SetTidAddress, "set_tid_address";
+    97 => SYSCALL_UNSHARE, Unshare, "unshare";
+    98 => SYSCALL_FUTEX, Futex, "futex";
+    99 => SYSCALL_SET_ROBUST_LIST, SetRobustList, "set_robust_list";
+    100 => SYSCALL_GET_ROBUST_LIST, GetRobustList, "get_robust_list";
+    101 => SYSCALL_NANOSLEEP, Nanosleep, "nanosleep";
+    102 => SYSCALL_GETITIMER, Getitimer, "getitimer";
+    103 => SYSCALL_SETITIMER, Setitimer, "setitimer";
+    104 => SYSCALL_KEXEC_LOAD, KexecLoad, "kexec_load";
+    105 => SYSCALL_INIT_MODULE, InitModule, "init_module";
+    106 => SYSCALL_DELETE_MODULE, DeleteModule, "delete_module";
+    107 => SYSCALL_TIMER_CREATE, TimerCreate, "timer_create";
+    108 => SYSCALL_TIMER_GETTIME, TimerGettime, "timer_gettime";
+    109 => SYSCALL_TIMER_GETOVERRUN, TimerGetoverrun, "timer_getoverrun";
+    110 => SYSCALL_TIMER_SETTIME, TimerSettime, "timer_settime";
+    111 => SYSCALL_TIMER_DELETE, TimerDelete, "timer_delete";
+    112 => SYSCALL_CLOCK_SETTIME, ClockSettime, "clock_settime";
+    113 => SYSCALL_CLOCK_GETTIME, ClockGettime, "clock_gettime";
+    114 => SYSCALL_CLOCK_GETRES, ClockGetres, "clock_getres";
+    115 => SYSCALL_CLOCK_NANOSLEEP, ClockNanosleep, "clock_nanosleep";
+    116 => SYSCALL_SYSLOG, Syslog, "syslog";
+    117 => SYSCALL_PTRACE, Ptrace, "ptrace";
+    118 => SYSCALL_SCHED_SETPARAM, SchedSetparam, "sched_setparam";
+    119 => SYSCALL_SCHED_SETSCHEDULER, SchedSetscheduler, "sched_setscheduler";
+    120 => SYSCALL_SCHED_GETSCHEDULER, SchedGetscheduler, "sched_getscheduler";
+    121 => SYSCALL_SCHED_GETPARAM, SchedGetparam, "sched_getparam";
+    122 => SYSCALL_SCHED_SETAFFINITY, SchedSetaffinity, "sched_setaffinity";
+    123 => SYSCALL_SCHED_GETAFFINITY, SchedGetaffinity, "sched_getaffinity";
+    124 => SYSCALL_YIELD, SchedYield, "sched_yield";
+    125 => SYSCALL_SCHED_GET_PRIORITY_MAX, SchedGetPriorityMax, "sched_get_priority_max";
+    126 => SYSCALL_SCHED_GET_PRIORITY_MIN, SchedGetPriorityMin, "sched_get_priority_min";
+    127 => SYSCALL_SCHED_RR_GET_INTERVAL, SchedRrGetInterval, "sched_rr_get_interval";
+    128 => SYSCALL_RESTART_SYSCALL, RestartSyscall, "restart_syscall";
+    129 => SYSCALL_KILL, Kill, "kill";
+    130 => SYSCALL_TKILL, Tkill, "tkill";
+    131 => SYSCALL_TGKILL, Tgkill, "tgkill";
+    132 => SYSCALL_SIGALTSTACK, Sigaltstack, "sigaltstack";
+    133 => SYSCALL_SIGSUSPEND, RtSigsuspend, "rt_sigsuspend";
+    134 => SYSCALL_SIGACTION, RtSigaction, "rt_sigaction";
+    135 => SYSCALL_SIGPROCMASK, RtSigprocmask, "rt_sigprocmask";
+    136 => SYSCALL_RT_SIGPENDING, RtSigpending, "rt_sigpending";
+    137 => SYSCALL_SIGTIMEDWAIT, RtSigtimedwait, "rt_sigtimedwait";
+    138 => SYSCALL_RT_SIGQUEUEINFO, RtSigqueueinfo, "rt_sigqueueinfo";
+    139 => SYSCALL_SIGRETURN, RtSigreturn, "rt_sigreturn";
+    140 => SYSCALL_SETPRIORITY, Setpriority, "setpriority";
+    141 => SYSCALL_GETPRIORITY, Getpriority, "getpriority";
+    142 => SYSCALL_REBOOT, Reboot, "reboot";
+    143 => SYSCALL_SETREGID, Setregid, "setregid";
+    144 => SYSCALL_SETGID, Setgid, "setgid";
+    145 => SYSCALL_SETREUID, Setreuid, "setreuid";
+    146 => SYSCALL_SETUID, Setuid, "setuid";
+    147 => SYSCALL_SETRESUID, Setresuid, "setresuid";
+    148 => SYSCALL_GETRESUID, Getresuid, "getresuid";
+    149 => SYSCALL_SETRESGID, Setresgid, "setresgid";
+    150 => SYSCALL_GETRESGID, Getresgid, "getresgid";
+    151 => SYSCALL_SETFSUID, Setfsuid, "setfsuid";
+    152 => SYSCALL_SETFSGID, Setfsgid, "setfsgid";
+    153 => SYSCALL_TIMES, Times, "times";
+    154 => SYSCALL_SETPGID, Setpgid, "setpgid";
+    155 => SYSCALL_GETPGID, Getpgid, "getpgid";
+    156 => SYSCALL_GETSID, Getsid, "getsid";
+    157 => SYSCALL_SETSID, Setsid, "setsid";
+    158 => SYSCALL_GETGROUPS, Getgroups, "getgroups";
+    159 => SYSCALL_SETGROUPS, Setgroups, "setgroups";
+    160 => SYSCALL_UNAME, Uname, "uname";
+    161 => SYSCALL_SETHOSTNAME, Sethostname, "sethostname";
+    162 => SYSCALL_SETDOMAINNAME, Setdomainname, "setdomainname";
+    #[cfg(not(feature = "arch-loongarch64"))]
+    163 => SYSCALL_GETRLIMIT, Getrlimit, "getrlimit";
+    #[cfg(not(feature = "arch-loongarch64"))]
+    164 => SYSCALL_SETRLIMIT, Setrlimit, "setrlimit";
+    165 => SYSCALL_GETRUSAGE, Getrusage, "getrusage";
+    166 => SYSCALL_UMASK, Umask, "umask";
+    167 => SYSCALL_PRCTL, Prctl, "prctl";
+    168 => SYSCALL_GETCPU, Getcpu, "getcpu";
+    169 => SYSCALL_GET_TIME_OF_DAY, Gettimeofday, "gettimeofday";
+    170 => SYSCALL_SETTIMEOFDAY, Settimeofday, "settimeofday";
+    171 => SYSCALL_ADJTIMEX, Adjtimex, "adjtimex";
+    172 => SYSCALL_GETPID, Getpid, "getpid";
+    173 => SYSCALL_GETPPID, Getppid, "getppid";
+    174 => SYSCALL_GETUID, Getuid, "getuid";
+    175 => SYSCALL_GETEUID, Geteuid, "geteuid";
+    176 => SYSCALL_GETGID, Getgid, "getgid";
+    177 => SYSCALL_GETEGID, Getegid, "getegid";
+    178 => SYSCALL_GETTID, Gettid, "gettid";
+    179 => SYSCALL_SYSINFO, Sysinfo, "sysinfo";
+    180 => SYSCALL_MQ_OPEN, MqOpen, "mq_open";
+    181 => SYSCALL_MQ_UNLINK, MqUnlink, "mq_unlink";
+    182 => SYSCALL_MQ_TIMEDSEND, MqTimedsend, "mq_timedsend";
+    183 => SYSCALL_MQ_TIMEDRECEIVE, MqTimedreceive, "mq_timedreceive";
+    184 => SYSCALL_MQ_NOTIFY, MqNotify, "mq_notify";
+    185 => SYSCALL_MQ_GETSETATTR, MqGetsetattr, "mq_getsetattr";
+    186 => SYSCALL_MSGGET, Msgget, "msgget";
+    187 => SYSCALL_MSGCTL, Msgctl, "msgctl";
+    188 => SYSCALL_MSGRCV, Msgrcv, "msgrcv";
+    189 => SYSCALL_MSGSND, Msgsnd, "msgsnd";
+    190 => SYSCALL_SEMGET, Semget, "semget";
+    191 => SYSCALL_SEMCTL, Semctl, "semctl";
+    192 => SYSCALL_SEMTIMEDOP, Semtimedop, "semtimedop";
+    193 => SYSCALL_SEMOP, Semop, "semop";
+    194 => SYSCALL_SHMGET, Shmget, "shmget";
+    195 => SYSCALL_SHAMCTL, Shmctl, "shmctl";
+    196 => SYSCALL_SHAMAT, Shmat, "shmat";
+    197 => SYSCALL_SHAMDT, Shmdt, "shmdt";
+    198 => SYSCALL_SOCKET, Socket, "socket";
+    199 => SYSCALL_SOCKETPAIR, Socketpair, "socketpair";
+    200 => SYSCALL_BIND, Bind, "bind";
+    201 => SYSCALL_LISTEN, Listen, "listen";
+    202 => SYSCALL_ACCEPT, Accept, "accept";
+    203 => SYSCALL_CONNECT, Connect, "connect";
+    204 => SYSCALL_GETSOCKNAME, Getsockname, "getsockname";
+    205 => SYSCALL_GETPEERNAME, Getpeername, "getpeername";
+    206 => SYSCALL_SENDTO, Sendto, "sendto";
+    207 => SYSCALL_RECVFROM, Recvfrom, "recvfrom";
+    208 => SYSCALL_SETSOCKOPT, Setsockopt, "setsockopt";
+    209 => SYSCALL_GETSOCKOPT, Getsockopt, "getsockopt";
+    210 => SYSCALL_SHUTDOWN, Shutdown, "shutdown";
+    211 => SYSCALL_SENDMSG, Sendmsg, "sendmsg";
+    212 => SYSCALL_RECVMSG, Recvmsg, "recvmsg";
+    213 => SYSCALL_READAHEAD, Readahead, "readahead";
+    214 => SYSCALL_BRK, Brk, "brk";
+    215 => SYSCALL_MUNMAP, Munmap, "munmap";
+    216 => SYSCALL_MREMAP, Mremap, "mremap";
+    217 => SYSCALL_ADD_KEY, AddKey, "add_key";
+    218 => SYSCALL_REQUEST_KEY, RequestKey, "request_key";
+    219 => SYSCALL_KEYCTL, Keyctl, "keyctl";
+    220 => SYSCALL_CLONE, Clone, "clone";
+    221 => SYSCALL_EXECVE, Execve, "execve";
+    222 => SYSCALL_MMAP, Mmap, "mmap";
+    223 => SYSCALL_FADVISE64, Fadvise64, "fadvise64";
+    224 => SYSCALL_SWAPON, Swapon, "swapon";
+    225 => SYSCALL_SWAPOFF, Swapoff, "swapoff";
+    226 => SYSCALL_MPROTECT, Mprotect, "mprotect";
+    227 => SYSCALL_MSYNC, Msync, "msync";
+    228 => SYSCALL_MLOCK, Mlock, "mlock";
+    229 => SYSCALL_MUNLOCK, Munlock, "munlock";
+    230 => SYSCALL_MLOCKALL, Mlockall, "mlockall";
+    231 => SYSCALL_MUNLOCKALL, Munlockall, "munlockall";
+    232 => SYSCALL_MINCORE, Mincore, "mincore";
+    233 => SYSCALL_MADVISE, Madvise, "madvise";
+    234 => SYSCALL_REMAP_FILE_PAGES, RemapFilePages, "remap_file_pages";
+    235 => SYSCALL_MBIND, Mbind, "mbind";
+    236 => SYSCALL_GET_MEMPOLICY, GetMempolicy, "get_mempolicy";
+    237 => SYSCALL_SET_MEMPOLICY, SetMempolicy, "set_mempolicy";
+    238 => SYSCALL_MIGRATE_PAGES, MigratePages, "migrate_pages";
+    239 => SYSCALL_MOVE_PAGES, MovePages, "move_pages";
+    240 => SYSCALL_RT_TGSIGQUEUEINFO, RtTgsigqueueinfo, "rt_tgsigqueueinfo";
+    241 => SYSCALL_PERF_EVENT_OPEN, PerfEventOpen, "perf_event_open";
+    242 => SYSCALL_ACCEPT4, Accept4, "accept4";
+    243 => SYSCALL_RECVMMSG, Recvmmsg, "recvmmsg";
+    #[cfg(any(
+        feature = "arch-riscv64",
+        all(
+            feature = "riscv",
+            not(any(feature = "arch-aarch64", feature = "arch-loongarch64"))
+        )
+    ))]
+    258 => SYSCALL_RISCV_HWPROBE, RiscvHwprobe, "riscv_hwprobe";
+    #[cfg(any(
+        feature = "arch-riscv64",
+        all(
+            feature = "riscv",
+            not(any(feature = "arch-aarch64", feature = "arch-loongarch64"))
+        )
+    ))]
+    259 => SYSCALL_RISCV_FLUSH_ICACHE, RiscvFlushIcache, "riscv_flush_icache";
+    260 => SYSCALL_WAIT4, Wait4, "wait4";
+    261 => SYSCALL_PRLIMIT, Prlimit64, "prlimit64";
+    262 => SYSCALL_FANOTIFY_INIT, FanotifyInit, "fanotify_init";
+    263 => SYSCALL_FANOTIFY_MARK, FanotifyMark, "fanotify_mark";
+    264 => SYSCALL_NAME_TO_HANDLE_AT, NameToHandleAt, "name_to_handle_at";
+    265 => SYSCALL_OPEN_BY_HANDLE_AT, OpenByHandleAt, "open_by_handle_at";
+    266 => SYSCALL_CLOCK_ADJTIME, ClockAdjtime, "clock_adjtime";
+    267 => SYSCALL_SYNCFS, Syncfs, "syncfs";
+    268 => SYSCALL_SETNS, Setns, "setns";
+    269 => SYSCALL_SENDMMSG, Sendmmsg, "sendmmsg";
+    270 => SYSCALL_PROCESS_VM_READV, ProcessVmReadv, "process_vm_readv";
+    271 => SYSCALL_PROCESS_VM_WRITEV, ProcessVmWritev, "process_vm_writev";
+    272 => SYSCALL_KCMP, Kcmp, "kcmp";
+    273 => SYSCALL_FINIT_MODULE, FinitModule, "finit_module";
+    274 => SYSCALL_SCHED_SETATTR, SchedSetattr, "sched_setattr";
+    275 => SYSCALL_SCHED_GETATTR, SchedGetattr, "sched_getattr";
+    276 => SYSCALL_RENAMEAT2, Renameat2, "renameat2";
+    277 => SYSCALL_SECCOMP, Seccomp, "seccomp";
+    278 => SYSCALL_GETRANDOM, Getrandom, "getrandom";
+    279 => SYSCALL_MEMFD_CREATE, MemfdCreate, "memfd_create";
+    280 => SYSCALL_BPF, Bpf, "bpf";
+    281 => SYSCALL_EXECVEAT, Execveat, "execveat";
+    282 => SYSCALL_USERFAULTFD, Userfaultfd, "userfaultfd";
+    283 => SYSCALL_MEMBARRIER, Membarrier, "membarrier";
+    284 => SYSCALL_MLOCK2, Mlock2, "mlock2";
+    285 => SYSCALL_COPY_FILE_RANGE, CopyFileRange, "copy_file_range";
+    286 => SYSCALL_PREADV2, Preadv2, "preadv2";
+    287 => SYSCALL_PWRITEV2, Pwritev2, "pwritev2";
+    288 => SYSCALL_PKEY_MPROTECT, PkeyMprotect, "pkey_mprotect";
+    289 => SYSCALL_PKEY_ALLOC, PkeyAlloc, "pkey_alloc";
+    290 => SYSCALL_PKEY_FREE, PkeyFree, "pkey_free";
+    291 => SYSCALL_STATX, Statx, "statx";
+    292 => SYSCALL_IO_PGETEVENTS, IoPgetevents, "io_pgetevents";
+    293 => SYSCALL_RSEQ, Rseq, "rseq";
+    294 => SYSCALL_KEXEC_FILE_LOAD, KexecFileLoad, "kexec_file_load";
+    424 => SYSCALL_PIDFD_SEND_SIGNAL, PidfdSendSignal, "pidfd_send_signal";
+    425 => SYSCALL_IO_URING_SETUP, IoUringSetup, "io_uring_setup";
+    426 => SYSCALL_IO_URING_ENTER, IoUringEnter, "io_uring_enter";
+    427 => SYSCALL_IO_URING_REGISTER, IoUringRegister, "io_uring_register";
+    428 => SYSCALL_OPEN_TREE, OpenTree, "open_tree";
+    429 => SYSCALL_MOVE_MOUNT, MoveMount, "move_mount";
+    430 => SYSCALL_FSOPEN, Fsopen, "fsopen";
+    431 => SYSCALL_FSCONFIG, Fsconfig, "fsconfig";
+    432 => SYSCALL_FSMOUNT, Fsmount, "fsmount";
+    433 => SYSCALL_FSPICK, Fspick, "fspick";
+    434 => SYSCALL_PIDFD_OPEN, PidfdOpen, "pidfd_open";
+    435 => SYSCALL_CLONE3, Clone3, "clone3";
+    436 => SYSCALL_CLOSE_RANGE, CloseRange, "close_range";
+    437 => SYSCALL_OPENAT2, Openat2, "openat2";
+    438 => SYSCALL_PIDFD_GETFD, PidfdGetfd, "pidfd_getfd";
+    439 => SYSCALL_FACCESSAT2, Faccessat2, "faccessat2";
+    440 => SYSCALL_PROCESS_MADVISE, ProcessMadvise, "process_madvise";
+    441 => SYSCALL_EPOLL_PWAIT2, EpollPwait2, "epoll_pwait2";
+    442 => SYSCALL_MOUNT_SETATTR, MountSetattr, "mount_setattr";
+    443 => SYSCALL_QUOTACTL_FD, QuotactlFd, "quotactl_fd";
+    444 => SYSCALL_LANDLOCK_CREATE_RULESET, LandlockCreateRuleset, "landlock_create_ruleset";
+    445 => SYSCALL_LANDLOCK_ADD_RULE, LandlockAddRule, "landlock_add_rule";
+    446 => SYSCALL_LANDLOCK_RESTRICT_SELF, LandlockRestrictSelf, "landlock_restrict_self";
+    #[cfg(not(feature = "arch-loongarch64"))]
+    447 => SYSCALL_MEMFD_SECRET, MemfdSecret, "memfd_secret";
+    448 => SYSCALL_PROCESS_MRELEASE, ProcessMrelease, "process_mrelease";
+    449 => SYSCALL_FUTEX_WAITV, FutexWaitv, "futex_waitv";
+    450 => SYSCALL_SET_MEMPOLICY_HOME_NODE, SetMempolicyHomeNode, "set_mempolicy_home_node";
+    451 => SYSCALL_CACHESTAT, Cachestat, "cachestat";
+    452 => SYSCALL_FCHMODAT2, Fchmodat2, "fchmodat2";
+    453 => SYSCALL_MAP_SHADOW_STACK, MapShadowStack, "map_shadow_stack";
+    454 => SYSCALL_FUTEX_WAKE, FutexWake, "futex_wake";
+    455 => SYSCALL_FUTEX_WAIT, FutexWait, "futex_wait";
+    456 => SYSCALL_FUTEX_REQUEUE, FutexRequeue, "futex_requeue";
+    457 => SYSCALL_STATMOUNT, Statmount, "statmount";
+    458 => SYSCALL_LISTMOUNT, Listmount, "listmount";
+    459 => SYSCALL_LSM_GET_SELF_ATTR, LsmGetSelfAttr, "lsm_get_self_attr";
+    460 => SYSCALL_LSM_SET_SELF_ATTR, LsmSetSelfAttr, "lsm_set_self_attr";
+    461 => SYSCALL_LSM_LIST_MODULES, LsmListModules, "lsm_list_modules";
+    462 => SYSCALL_MSEAL, Mseal, "mseal";
+}
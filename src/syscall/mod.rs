@@ -0,0 +1,196 @@
+//! Linux system call numbers.
+//!
+//! The numbering depends on the target architecture and is selected by one of
+//! the `arch-*` features. riscv64, aarch64 and loongarch64 share the
+//! asm-generic table, x86_64 has its own. Without any `arch-*` feature the
+//! asm-generic table is used.
+
+#[cfg(any(
+    all(feature = "arch-riscv64", feature = "arch-aarch64"),
+    all(feature = "arch-riscv64", feature = "arch-x86_64"),
+    all(feature = "arch-riscv64", feature = "arch-loongarch64"),
+    all(feature = "arch-aarch64", feature = "arch-x86_64"),
+    all(feature = "arch-aarch64", feature = "arch-loongarch64"),
+    all(feature = "arch-x86_64", feature = "arch-loongarch64"),
+))]
+compile_error!("only one `arch-*` feature can be enabled");
+
+use core::fmt::Display;
+
+use crate::LinuxErrno;
+
+/// Declares the `SYSCALL_*` constants and the [`Syscall`] enum of one
+/// architecture from a single `number => CONST, Variant, "name";` table.
+macro_rules! syscall_table {
+    ($($(#[$attr:meta])* $num:literal => $konst:ident, $variant:ident, $name:literal;)*) => {
+        $(
+            $(#[$attr])*
+            pub const $konst: usize = $num;
+        )*
+
+        /// Linux system call of the selected architecture.
+        ///
+        /// The discriminant is the number passed in the syscall register.
+        #[repr(usize)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Syscall {
+            $(
+                $(#[$attr])*
+                $variant = $num,
+            )*
+        }
+
+        impl Syscall {
+            /// The canonical Linux name of the system call, e.g. `"openat"`.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(
+                        $(#[$attr])*
+                        Self::$variant => $name,
+                    )*
+                }
+            }
+        }
+
+        impl TryFrom<usize> for Syscall {
+            type Error = super::UnknownSyscall;
+
+            fn try_from(value: usize) -> Result<Self, Self::Error> {
+                match value {
+                    $(
+                        $(#[$attr])*
+                        $num => Ok(Self::$variant),
+                    )*
+                    _ => Err(super::UnknownSyscall(value)),
+                }
+            }
+        }
+
+        impl core::str::FromStr for Syscall {
+            type Err = super::ParseSyscallError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $(
+                        $(#[$attr])*
+                        $name => Ok(Self::$variant),
+                    )*
+                    _ => Err(super::ParseSyscallError),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "arch-x86_64"))]
+mod generic;
+mod signature;
+mod trace;
+#[cfg(feature = "arch-x86_64")]
+mod x86_64;
+
+#[cfg(not(feature = "arch-x86_64"))]
+pub use generic::*;
+pub use signature::*;
+pub use trace::*;
+#[cfg(feature = "arch-x86_64")]
+pub use x86_64::*;
+
+/// The number does not belong to any system call of the selected architecture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownSyscall(pub usize);
+
+impl From<UnknownSyscall> for LinuxErrno {
+    fn from(_: UnknownSyscall) -> Self {
+        LinuxErrno::ENOSYS
+    }
+}
+
+/// The string is not the name of any system call of the selected architecture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseSyscallError;
+
+impl From<Syscall> for usize {
+    fn from(value: Syscall) -> Self {
+        value as usize
+    }
+}
+
+impl Display for Syscall {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+pub fn syscall_name(id: usize) -> &'static str {
+    Syscall::try_from(id).map_or("unknown", Syscall::name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_name_round_trip() {
+        let mut count = 0;
+        for num in 0..1024 {
+            let Ok(syscall) = Syscall::try_from(num) else {
+                assert_eq!(syscall_name(num), "unknown");
+                continue;
+            };
+            count += 1;
+            assert_eq!(usize::from(syscall), num);
+            assert_eq!(syscall_name(num), syscall.name());
+            assert_eq!(alloc::format!("{}", syscall), syscall.name());
+            assert_eq!(syscall.name().parse(), Ok(syscall));
+        }
+        assert!(count > 250);
+    }
+
+    #[test]
+    fn unknown_syscall() {
+        assert_eq!(Syscall::try_from(1024), Err(UnknownSyscall(1024)));
+        assert_eq!(
+            LinuxErrno::from(UnknownSyscall(usize::MAX)),
+            LinuxErrno::ENOSYS
+        );
+        assert_eq!("sbrk".parse::<Syscall>(), Err(ParseSyscallError));
+        assert_eq!("".parse::<Syscall>(), Err(ParseSyscallError));
+    }
+
+    #[test]
+    #[cfg(not(feature = "arch-x86_64"))]
+    fn generic_numbers() {
+        assert_eq!(SYSCALL_OPENAT, 56);
+        assert_eq!(Syscall::try_from(56), Ok(Syscall::Openat));
+        assert_eq!(Syscall::try_from(93), Ok(Syscall::Exit));
+        // renameat 只在 aarch64 上保留
+        #[cfg(feature = "arch-aarch64")]
+        assert_eq!(Syscall::try_from(38), Ok(Syscall::Renameat));
+        #[cfg(not(feature = "arch-aarch64"))]
+        {
+            assert!(Syscall::try_from(38).is_err());
+            assert!("renameat".parse::<Syscall>().is_err());
+        }
+        #[cfg(any(
+            feature = "arch-riscv64",
+            all(
+                feature = "riscv",
+                not(any(feature = "arch-aarch64", feature = "arch-loongarch64"))
+            )
+        ))]
+        assert_eq!(Syscall::try_from(258), Ok(Syscall::RiscvHwprobe));
+        #[cfg(any(feature = "arch-aarch64", feature = "arch-loongarch64"))]
+        assert!("riscv_hwprobe".parse::<Syscall>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "arch-x86_64")]
+    fn x86_64_numbers() {
+        assert_eq!(SYSCALL_OPENAT, 257);
+        assert_eq!(Syscall::try_from(257), Ok(Syscall::Openat));
+        assert_eq!(Syscall::try_from(2), Ok(Syscall::Open));
+        assert_eq!(Syscall::try_from(264), Ok(Syscall::Renameat));
+        assert_eq!(Syscall::try_from(60), Ok(Syscall::Exit));
+    }
+}
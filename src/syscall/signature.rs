@@ -0,0 +1,857 @@
+//! Argument and return value descriptions of system calls, for tracing.
+
+use super::Syscall;
+
+/// How a raw system call argument should be interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgKind {
+    /// Signed integer.
+    Int,
+    /// Unsigned integer, usually a size or a count.
+    UInt,
+    /// Integer that is best shown in hexadecimal, e.g. flags without a type.
+    Hex,
+    /// File descriptor.
+    Fd,
+    /// Directory file descriptor, may be `AT_FDCWD`.
+    DirFd,
+    /// Process, thread or process group id.
+    Pid,
+    /// User space pointer to memory of no particular type.
+    Ptr,
+    /// User space pointer to a NUL-terminated path or name.
+    Path,
+    /// Permission and file type bits, see [`InodeMode`](crate::io::InodeMode).
+    Mode,
+    /// [`OpenFlags`](crate::io::OpenFlags).
+    OpenFlags,
+    /// [`StatFlags`](crate::io::StatFlags).
+    StatFlags,
+    /// [`StatxMask`](crate::io::StatxMask).
+    StatxMask,
+    /// [`UnlinkatFlags`](crate::io::UnlinkatFlags).
+    UnlinkatFlags,
+    /// [`LinkFlags`](crate::io::LinkFlags).
+    LinkFlags,
+    /// [`FaccessatMode`](crate::io::FaccessatMode).
+    FaccessatMode,
+    /// [`FaccessatFlags`](crate::io::FaccessatFlags).
+    FaccessatFlags,
+    /// [`Renameat2Flags`](crate::io::Renameat2Flags).
+    Renameat2Flags,
+    /// [`MountFlags`](crate::io::MountFlags).
+    MountFlags,
+    /// [`ProtFlags`](crate::io::ProtFlags).
+    ProtFlags,
+    /// [`MMapType`](crate::io::MMapType) in the low bits, [`MMapFlags`](crate::io::MMapFlags)
+    /// in the rest.
+    MMapFlags,
+    /// [`CloneFlags`](crate::task::CloneFlags), with the exit signal in the low byte.
+    CloneFlags,
+    /// [`WaitOptions`](crate::task::WaitOptions).
+    WaitOptions,
+    /// [`EventFdFlags`](crate::epoll::EventFdFlags).
+    EventFdFlags,
+    /// [`TimerFdFlags`](crate::time::TimerFdFlags).
+    TimerFdFlags,
+    /// [`FutexOp`](crate::ipc::FutexOp).
+    FutexOp,
+    /// [`ClockId`](crate::time::ClockId).
+    ClockId,
+    /// [`TimerType`](crate::time::TimerType).
+    TimerType,
+    /// [`SignalNumber`](crate::signal::SignalNumber).
+    Signal,
+    /// [`SigProcMaskHow`](crate::signal::SigProcMaskHow).
+    SigProcMaskHow,
+    /// `lseek` whence, see [`SeekFrom`](crate::io::SeekFrom).
+    Whence,
+    /// [`Fcntl64Cmd`](crate::io::Fcntl64Cmd).
+    FcntlCmd,
+    /// ioctl request, see [`IoctlCmd`](crate::ioctl::IoctlCmd).
+    IoctlCmd,
+    /// [`EpollCtlOp`](crate::epoll::EpollCtlOp).
+    EpollCtlOp,
+    /// [`PrctlOp`](crate::sys::PrctlOp).
+    PrctlOp,
+    /// [`SyslogAction`](crate::sys::SyslogAction).
+    SyslogAction,
+    /// [`RusageFlag`](crate::sys::RusageFlag).
+    RusageWho,
+    /// [`PrLimitResType`](crate::PrLimitResType).
+    Resource,
+    /// [`ShmGetFlags`](crate::ipc::ShmGetFlags).
+    ShmGetFlags,
+    /// [`ShmAtFlags`](crate::ipc::ShmAtFlags).
+    ShmAtFlags,
+    /// [`ShmCtlCmd`](crate::ipc::ShmCtlCmd).
+    ShmCtlCmd,
+    /// [`Domain`](crate::net::Domain).
+    Domain,
+    /// [`SocketType`](crate::net::SocketType), with `SOCK_NONBLOCK` and `SOCK_CLOEXEC`.
+    SocketType,
+    /// [`SocketLevel`](crate::net::SocketLevel).
+    SocketLevel,
+    /// [`ShutdownFlag`](crate::net::ShutdownFlag).
+    ShutdownHow,
+    /// Pointer to a [`TimeSpec`](crate::time::TimeSpec).
+    TimeSpecPtr,
+    /// Pointer to a [`TimeVal`](crate::time::TimeVal).
+    TimeValPtr,
+    /// Pointer to an [`ITimerVal`](crate::time::ITimerVal).
+    ITimerValPtr,
+    /// Pointer to an [`ITimeSpec`](crate::time::ITimeSpec).
+    ITimeSpecPtr,
+    /// Pointer to a signal set, see [`SimpleBitSet`](crate::signal::SimpleBitSet).
+    SigSetPtr,
+    /// Pointer to a [`SigAction`](crate::signal::SigAction).
+    SigActionPtr,
+    /// Pointer to a [`SigInfo`](crate::signal::SigInfo).
+    SigInfoPtr,
+    /// Pointer to a [`FileStat`](crate::io::FileStat).
+    StatPtr,
+    /// Pointer to an [`OpenHow`](crate::io::OpenHow).
+    OpenHowPtr,
+    /// Pointer to a [`Statx`](crate::io::Statx).
+    StatxPtr,
+    /// Pointer to an [`FsStat`](crate::io::FsStat).
+    StatFsPtr,
+    /// Pointer to an array of [`IoVec`](crate::io::IoVec).
+    IoVecPtr,
+    /// Pointer to an array of [`PollFd`](crate::io::PollFd).
+    PollFdPtr,
+    /// Pointer to an [`EpollEvent`](crate::epoll::EpollEvent) or an array of them.
+    EpollEventPtr,
+    /// Pointer to an [`RLimit64`](crate::RLimit64).
+    RLimitPtr,
+    /// Pointer to an [`Rusage`](crate::sys::Rusage).
+    RusagePtr,
+    /// Pointer to a [`Times`](crate::time::Times).
+    TimesPtr,
+    /// Pointer to a [`Sysinfo`](crate::sys::Sysinfo).
+    SysinfoPtr,
+    /// Pointer to a socket address, e.g. [`SocketAddrInRaw`](crate::net::SocketAddrInRaw).
+    SockAddrPtr,
+}
+
+/// How the return value of a system call should be interpreted when it is not
+/// an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetKind {
+    /// Zero on success.
+    Errno,
+    /// Signed integer.
+    Int,
+    /// New file descriptor.
+    Fd,
+    /// Process or thread id.
+    Pid,
+    /// Number of bytes.
+    Size,
+    /// User space address.
+    Addr,
+    /// The system call does not return.
+    NoReturn,
+}
+
+/// One argument of a system call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyscallArg {
+    /// Argument name as in the man page.
+    pub name: &'static str,
+    pub kind: ArgKind,
+}
+
+/// Arguments and return value of a system call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyscallSignature {
+    /// Arguments in register order.
+    pub args: &'static [SyscallArg],
+    pub ret: RetKind,
+}
+
+macro_rules! sig {
+    ($ret:ident $(, $name:literal: $kind:ident)* $(,)?) => {
+        SyscallSignature {
+            args: &[$(SyscallArg {
+                name: $name,
+                kind: ArgKind::$kind,
+            }),*],
+            ret: RetKind::$ret,
+        }
+    };
+}
+
+/// Returns the signature of `syscall`, or `None` if it is not described yet.
+pub const fn syscall_signature(syscall: Syscall) -> Option<SyscallSignature> {
+    let signature = match syscall {
+        // File descriptors and I/O
+        Syscall::Read => sig!(Size, "fd": Fd, "buf": Ptr, "count": UInt),
+        Syscall::Write => sig!(Size, "fd": Fd, "buf": Ptr, "count": UInt),
+        Syscall::Readv => sig!(Size, "fd": Fd, "iov": IoVecPtr, "iovcnt": UInt),
+        Syscall::Writev => sig!(Size, "fd": Fd, "iov": IoVecPtr, "iovcnt": UInt),
+        Syscall::Pread64 => sig!(Size, "fd": Fd, "buf": Ptr, "count": UInt, "offset": Int),
+        Syscall::Pwrite64 => sig!(Size, "fd": Fd, "buf": Ptr, "count": UInt, "offset": Int),
+        Syscall::Preadv => sig!(
+            Size,
+            "fd": Fd,
+            "iov": IoVecPtr,
+            "iovcnt": UInt,
+            "pos_l": Int,
+            "pos_h": Int,
+        ),
+        Syscall::Pwritev => sig!(
+            Size,
+            "fd": Fd,
+            "iov": IoVecPtr,
+            "iovcnt": UInt,
+            "pos_l": Int,
+            "pos_h": Int,
+        ),
+        Syscall::Lseek => sig!(Int, "fd": Fd, "offset": Int, "whence": Whence),
+        Syscall::Close => sig!(Errno, "fd": Fd),
+        Syscall::Dup => sig!(Fd, "oldfd": Fd),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Dup2 => sig!(Fd, "oldfd": Fd, "newfd": Fd),
+        Syscall::Dup3 => sig!(Fd, "oldfd": Fd, "newfd": Fd, "flags": OpenFlags),
+        Syscall::Fcntl => sig!(Int, "fd": Fd, "cmd": FcntlCmd, "arg": Hex),
+        Syscall::Ioctl => sig!(Int, "fd": Fd, "request": IoctlCmd, "arg": Hex),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Pipe => sig!(Errno, "pipefd": Ptr),
+        Syscall::Pipe2 => sig!(Errno, "pipefd": Ptr, "flags": OpenFlags),
+        Syscall::Sendfile => sig!(Size, "out_fd": Fd, "in_fd": Fd, "offset": Ptr, "count": UInt),
+        Syscall::CopyFileRange => sig!(
+            Size,
+            "fd_in": Fd,
+            "off_in": Ptr,
+            "fd_out": Fd,
+            "off_out": Ptr,
+            "len": UInt,
+            "flags": Hex,
+        ),
+        Syscall::Splice => sig!(
+            Size,
+            "fd_in": Fd,
+            "off_in": Ptr,
+            "fd_out": Fd,
+            "off_out": Ptr,
+            "len": UInt,
+            "flags": Hex,
+        ),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Getdents => sig!(Size, "fd": Fd, "dirp": Ptr, "count": UInt),
+        Syscall::Getdents64 => sig!(Size, "fd": Fd, "dirp": Ptr, "count": UInt),
+        Syscall::Fsync => sig!(Errno, "fd": Fd),
+        Syscall::Fdatasync => sig!(Errno, "fd": Fd),
+        Syscall::Sync => sig!(Errno),
+        Syscall::Syncfs => sig!(Errno, "fd": Fd),
+        Syscall::Ftruncate => sig!(Errno, "fd": Fd, "length": Int),
+        Syscall::Fallocate => sig!(Errno, "fd": Fd, "mode": Hex, "offset": Int, "len": Int),
+        Syscall::Fadvise64 => sig!(Errno, "fd": Fd, "offset": Int, "len": Int, "advice": Int),
+        Syscall::Flock => sig!(Errno, "fd": Fd, "operation": Hex),
+        // Polling and event notification
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Select => sig!(
+            Int,
+            "nfds": Int,
+            "readfds": Ptr,
+            "writefds": Ptr,
+            "exceptfds": Ptr,
+            "timeout": TimeValPtr,
+        ),
+        Syscall::Pselect6 => sig!(
+            Int,
+            "nfds": Int,
+            "readfds": Ptr,
+            "writefds": Ptr,
+            "exceptfds": Ptr,
+            "timeout": TimeSpecPtr,
+            "sigmask": Ptr,
+        ),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Poll => sig!(Int, "fds": PollFdPtr, "nfds": UInt, "timeout": Int),
+        Syscall::Ppoll => sig!(
+            Int,
+            "fds": PollFdPtr,
+            "nfds": UInt,
+            "tmo_p": TimeSpecPtr,
+            "sigmask": SigSetPtr,
+            "sigsetsize": UInt,
+        ),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::EpollCreate => sig!(Fd, "size": Int),
+        Syscall::EpollCreate1 => sig!(Fd, "flags": OpenFlags),
+        Syscall::EpollCtl => sig!(
+            Errno,
+            "epfd": Fd,
+            "op": EpollCtlOp,
+            "fd": Fd,
+            "event": EpollEventPtr,
+        ),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::EpollWait => sig!(
+            Int,
+            "epfd": Fd,
+            "events": EpollEventPtr,
+            "maxevents": Int,
+            "timeout": Int,
+        ),
+        Syscall::EpollPwait => sig!(
+            Int,
+            "epfd": Fd,
+            "events": EpollEventPtr,
+            "maxevents": Int,
+            "timeout": Int,
+            "sigmask": SigSetPtr,
+            "sigsetsize": UInt,
+        ),
+        Syscall::EpollPwait2 => sig!(
+            Int,
+            "epfd": Fd,
+            "events": EpollEventPtr,
+            "maxevents": Int,
+            "timeout": TimeSpecPtr,
+            "sigmask": SigSetPtr,
+            "sigsetsize": UInt,
+        ),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Eventfd => sig!(Fd, "initval": UInt),
+        Syscall::Eventfd2 => sig!(Fd, "initval": UInt, "flags": EventFdFlags),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Signalfd => sig!(Fd, "fd": Fd, "mask": SigSetPtr, "sizemask": UInt),
+        Syscall::Signalfd4 => sig!(
+            Fd,
+            "fd": Fd,
+            "mask": SigSetPtr,
+            "sizemask": UInt,
+            "flags": OpenFlags,
+        ),
+        Syscall::TimerfdCreate => sig!(Fd, "clockid": ClockId, "flags": OpenFlags),
+        Syscall::TimerfdSettime => sig!(
+            Errno,
+            "fd": Fd,
+            "flags": TimerFdFlags,
+            "new_value": ITimeSpecPtr,
+            "old_value": ITimeSpecPtr,
+        ),
+        Syscall::TimerfdGettime => sig!(Errno, "fd": Fd, "curr_value": ITimeSpecPtr),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::InotifyInit => sig!(Fd),
+        Syscall::InotifyInit1 => sig!(Fd, "flags": OpenFlags),
+        Syscall::InotifyAddWatch => sig!(Int, "fd": Fd, "pathname": Path, "mask": Hex),
+        Syscall::InotifyRmWatch => sig!(Errno, "fd": Fd, "wd": Int),
+        // Paths and file metadata
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Open => sig!(Fd, "pathname": Path, "flags": OpenFlags, "mode": Mode),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Creat => sig!(Fd, "pathname": Path, "mode": Mode),
+        Syscall::Openat => sig!(
+            Fd,
+            "dirfd": DirFd,
+            "pathname": Path,
+            "flags": OpenFlags,
+            "mode": Mode,
+        ),
+        Syscall::Openat2 => sig!(
+            Fd,
+            "dirfd": DirFd,
+            "pathname": Path,
+            "how": OpenHowPtr,
+            "size": UInt,
+        ),
+        Syscall::Getcwd => sig!(Size, "buf": Ptr, "size": UInt),
+        Syscall::Chdir => sig!(Errno, "path": Path),
+        Syscall::Fchdir => sig!(Errno, "fd": Fd),
+        Syscall::Chroot => sig!(Errno, "path": Path),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Mkdir => sig!(Errno, "pathname": Path, "mode": Mode),
+        Syscall::Mkdirat => sig!(Errno, "dirfd": DirFd, "pathname": Path, "mode": Mode),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Mknod => sig!(Errno, "pathname": Path, "mode": Mode, "dev": Hex),
+        Syscall::Mknodat => sig!(Errno, "dirfd": DirFd, "pathname": Path, "mode": Mode, "dev": Hex),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Rmdir => sig!(Errno, "pathname": Path),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Unlink => sig!(Errno, "pathname": Path),
+        Syscall::Unlinkat => sig!(Errno, "dirfd": DirFd, "pathname": Path, "flags": UnlinkatFlags),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Link => sig!(Errno, "oldpath": Path, "newpath": Path),
+        Syscall::Linkat => sig!(
+            Errno,
+            "olddirfd": DirFd,
+            "oldpath": Path,
+            "newdirfd": DirFd,
+            "newpath": Path,
+            "flags": LinkFlags,
+        ),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Symlink => sig!(Errno, "target": Path, "linkpath": Path),
+        Syscall::Symlinkat => sig!(Errno, "target": Path, "newdirfd": DirFd, "linkpath": Path),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Readlink => sig!(Size, "pathname": Path, "buf": Ptr, "bufsiz": UInt),
+        Syscall::Readlinkat => sig!(
+            Size,
+            "dirfd": DirFd,
+            "pathname": Path,
+            "buf": Ptr,
+            "bufsiz": UInt,
+        ),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Rename => sig!(Errno, "oldpath": Path, "newpath": Path),
+        #[cfg(any(feature = "arch-x86_64", feature = "arch-aarch64"))]
+        Syscall::Renameat => sig!(
+            Errno,
+            "olddirfd": DirFd,
+            "oldpath": Path,
+            "newdirfd": DirFd,
+            "newpath": Path,
+        ),
+        Syscall::Renameat2 => sig!(
+            Errno,
+            "olddirfd": DirFd,
+            "oldpath": Path,
+            "newdirfd": DirFd,
+            "newpath": Path,
+            "flags": Renameat2Flags,
+        ),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Access => sig!(Errno, "pathname": Path, "mode": FaccessatMode),
+        Syscall::Faccessat => sig!(Errno, "dirfd": DirFd, "pathname": Path, "mode": FaccessatMode),
+        Syscall::Faccessat2 => sig!(
+            Errno,
+            "dirfd": DirFd,
+            "pathname": Path,
+            "mode": FaccessatMode,
+            "flags": FaccessatFlags,
+        ),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Chmod => sig!(Errno, "pathname": Path, "mode": Mode),
+        Syscall::Fchmod => sig!(Errno, "fd": Fd, "mode": Mode),
+        Syscall::Fchmodat => sig!(Errno, "dirfd": DirFd, "pathname": Path, "mode": Mode),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Chown => sig!(Errno, "pathname": Path, "owner": Int, "group": Int),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Lchown => sig!(Errno, "pathname": Path, "owner": Int, "group": Int),
+        Syscall::Fchown => sig!(Errno, "fd": Fd, "owner": Int, "group": Int),
+        Syscall::Fchownat => sig!(
+            Errno,
+            "dirfd": DirFd,
+            "pathname": Path,
+            "owner": Int,
+            "group": Int,
+            "flags": StatFlags,
+        ),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Stat => sig!(Errno, "pathname": Path, "statbuf": StatPtr),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Lstat => sig!(Errno, "pathname": Path, "statbuf": StatPtr),
+        Syscall::Fstat => sig!(Errno, "fd": Fd, "statbuf": StatPtr),
+        Syscall::Newfstatat => sig!(
+            Errno,
+            "dirfd": DirFd,
+            "pathname": Path,
+            "statbuf": StatPtr,
+            "flags": StatFlags,
+        ),
+        Syscall::Statx => sig!(
+            Errno,
+            "dirfd": DirFd,
+            "pathname": Path,
+            "flags": StatFlags,
+            "mask": StatxMask,
+            "statxbuf": StatxPtr,
+        ),
+        Syscall::Statfs => sig!(Errno, "path": Path, "buf": StatFsPtr),
+        Syscall::Fstatfs => sig!(Errno, "fd": Fd, "buf": StatFsPtr),
+        Syscall::Truncate => sig!(Errno, "path": Path, "length": Int),
+        Syscall::Utimensat => sig!(
+            Errno,
+            "dirfd": DirFd,
+            "pathname": Path,
+            "times": TimeSpecPtr,
+            "flags": StatFlags,
+        ),
+        Syscall::Mount => sig!(
+            Errno,
+            "source": Path,
+            "target": Path,
+            "filesystemtype": Path,
+            "mountflags": MountFlags,
+            "data": Ptr,
+        ),
+        Syscall::Umount2 => sig!(Errno, "target": Path, "flags": Hex),
+        Syscall::MemfdCreate => sig!(Fd, "name": Path, "flags": Hex),
+        // Memory
+        Syscall::Brk => sig!(Addr, "addr": Ptr),
+        Syscall::Mmap => sig!(
+            Addr,
+            "addr": Ptr,
+            "length": UInt,
+            "prot": ProtFlags,
+            "flags": MMapFlags,
+            "fd": Fd,
+            "offset": Int,
+        ),
+        Syscall::Munmap => sig!(Errno, "addr": Ptr, "length": UInt),
+        Syscall::Mremap => sig!(
+            Addr,
+            "old_address": Ptr,
+            "old_size": UInt,
+            "new_size": UInt,
+            "flags": Hex,
+            "new_address": Ptr,
+        ),
+        Syscall::Mprotect => sig!(Errno, "addr": Ptr, "len": UInt, "prot": ProtFlags),
+        Syscall::Msync => sig!(Errno, "addr": Ptr, "length": UInt, "flags": Hex),
+        Syscall::Madvise => sig!(Errno, "addr": Ptr, "length": UInt, "advice": Int),
+        Syscall::Mlock => sig!(Errno, "addr": Ptr, "len": UInt),
+        Syscall::Munlock => sig!(Errno, "addr": Ptr, "len": UInt),
+        Syscall::Membarrier => sig!(Int, "cmd": Int, "flags": Hex, "cpu_id": Int),
+        Syscall::Shmget => sig!(Int, "key": Int, "size": UInt, "shmflg": ShmGetFlags),
+        Syscall::Shmctl => sig!(Int, "shmid": Int, "cmd": ShmCtlCmd, "buf": Ptr),
+        Syscall::Shmat => sig!(Addr, "shmid": Int, "shmaddr": Ptr, "shmflg": ShmAtFlags),
+        Syscall::Shmdt => sig!(Errno, "shmaddr": Ptr),
+        Syscall::Msgget => sig!(Int, "key": Int, "msgflg": Hex),
+        Syscall::Semget => sig!(Int, "key": Int, "nsems": Int, "semflg": Hex),
+        // Processes and threads
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Fork => sig!(Pid),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Vfork => sig!(Pid),
+        // x86_64 passes `child_tid` before `tls`, the asm-generic architectures
+        // the other way round.
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Clone => sig!(
+            Pid,
+            "flags": CloneFlags,
+            "stack": Ptr,
+            "parent_tid": Ptr,
+            "child_tid": Ptr,
+            "tls": Hex,
+        ),
+        #[cfg(not(feature = "arch-x86_64"))]
+        Syscall::Clone => sig!(
+            Pid,
+            "flags": CloneFlags,
+            "stack": Ptr,
+            "parent_tid": Ptr,
+            "tls": Hex,
+            "child_tid": Ptr,
+        ),
+        Syscall::Clone3 => sig!(Pid, "cl_args": Ptr, "size": UInt),
+        Syscall::Execve => sig!(Errno, "pathname": Path, "argv": Ptr, "envp": Ptr),
+        Syscall::Execveat => sig!(
+            Errno,
+            "dirfd": DirFd,
+            "pathname": Path,
+            "argv": Ptr,
+            "envp": Ptr,
+            "flags": StatFlags,
+        ),
+        Syscall::Exit => sig!(NoReturn, "status": Int),
+        Syscall::ExitGroup => sig!(NoReturn, "status": Int),
+        Syscall::Wait4 => sig!(
+            Pid,
+            "pid": Pid,
+            "wstatus": Ptr,
+            "options": WaitOptions,
+            "rusage": RusagePtr,
+        ),
+        Syscall::Waitid => sig!(
+            Errno,
+            "idtype": Int,
+            "id": Int,
+            "infop": SigInfoPtr,
+            "options": WaitOptions,
+            "rusage": RusagePtr,
+        ),
+        Syscall::SetTidAddress => sig!(Pid, "tidptr": Ptr),
+        Syscall::Futex => sig!(
+            Int,
+            "uaddr": Ptr,
+            "futex_op": FutexOp,
+            "val": UInt,
+            "timeout": TimeSpecPtr,
+            "uaddr2": Ptr,
+            "val3": UInt,
+        ),
+        Syscall::SetRobustList => sig!(Errno, "head": Ptr, "len": UInt),
+        Syscall::GetRobustList => sig!(Errno, "pid": Pid, "head_ptr": Ptr, "len_ptr": Ptr),
+        Syscall::Getpid => sig!(Pid),
+        Syscall::Getppid => sig!(Pid),
+        Syscall::Gettid => sig!(Pid),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Getpgrp => sig!(Pid),
+        Syscall::Getpgid => sig!(Pid, "pid": Pid),
+        Syscall::Setpgid => sig!(Errno, "pid": Pid, "pgid": Pid),
+        Syscall::Getsid => sig!(Pid, "pid": Pid),
+        Syscall::Setsid => sig!(Pid),
+        Syscall::Getuid => sig!(Int),
+        Syscall::Geteuid => sig!(Int),
+        Syscall::Getgid => sig!(Int),
+        Syscall::Getegid => sig!(Int),
+        Syscall::Setuid => sig!(Errno, "uid": Int),
+        Syscall::Setgid => sig!(Errno, "gid": Int),
+        Syscall::Setreuid => sig!(Errno, "ruid": Int, "euid": Int),
+        Syscall::Setregid => sig!(Errno, "rgid": Int, "egid": Int),
+        Syscall::Setresuid => sig!(Errno, "ruid": Int, "euid": Int, "suid": Int),
+        Syscall::Setresgid => sig!(Errno, "rgid": Int, "egid": Int, "sgid": Int),
+        Syscall::Getresuid => sig!(Errno, "ruid": Ptr, "euid": Ptr, "suid": Ptr),
+        Syscall::Getresgid => sig!(Errno, "rgid": Ptr, "egid": Ptr, "sgid": Ptr),
+        Syscall::Getgroups => sig!(Int, "size": Int, "list": Ptr),
+        Syscall::Setgroups => sig!(Errno, "size": Int, "list": Ptr),
+        Syscall::Umask => sig!(Int, "mask": Mode),
+        Syscall::Prctl => sig!(
+            Int,
+            "option": PrctlOp,
+            "arg2": Hex,
+            "arg3": Hex,
+            "arg4": Hex,
+            "arg5": Hex,
+        ),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::ArchPrctl => sig!(Int, "code": Hex, "addr": Hex),
+        Syscall::Unshare => sig!(Errno, "flags": CloneFlags),
+        Syscall::Setns => sig!(Errno, "fd": Fd, "nstype": CloneFlags),
+        Syscall::PidfdOpen => sig!(Fd, "pid": Pid, "flags": Hex),
+        Syscall::PidfdSendSignal => sig!(
+            Errno,
+            "pidfd": Fd,
+            "sig": Signal,
+            "info": SigInfoPtr,
+            "flags": Hex,
+        ),
+        Syscall::Prlimit64 => sig!(
+            Errno,
+            "pid": Pid,
+            "resource": Resource,
+            "new_limit": RLimitPtr,
+            "old_limit": RLimitPtr,
+        ),
+        #[cfg(not(feature = "arch-loongarch64"))]
+        Syscall::Getrlimit => sig!(Errno, "resource": Resource, "rlim": RLimitPtr),
+        #[cfg(not(feature = "arch-loongarch64"))]
+        Syscall::Setrlimit => sig!(Errno, "resource": Resource, "rlim": RLimitPtr),
+        Syscall::Getrusage => sig!(Errno, "who": RusageWho, "usage": RusagePtr),
+        Syscall::Times => sig!(Int, "buf": TimesPtr),
+        Syscall::Uname => sig!(Errno, "buf": Ptr),
+        Syscall::Sysinfo => sig!(Errno, "info": SysinfoPtr),
+        Syscall::Syslog => sig!(Int, "type": SyslogAction, "buf": Ptr, "len": Int),
+        Syscall::Getrandom => sig!(Size, "buf": Ptr, "buflen": UInt, "flags": Hex),
+        Syscall::Getcpu => sig!(Errno, "cpu": Ptr, "node": Ptr, "tcache": Ptr),
+        // Scheduling
+        Syscall::SchedYield => sig!(Errno),
+        Syscall::SchedSetparam => sig!(Errno, "pid": Pid, "param": Ptr),
+        Syscall::SchedGetparam => sig!(Errno, "pid": Pid, "param": Ptr),
+        Syscall::SchedSetscheduler => sig!(Errno, "pid": Pid, "policy": Int, "param": Ptr),
+        Syscall::SchedGetscheduler => sig!(Int, "pid": Pid),
+        Syscall::SchedSetaffinity => sig!(Errno, "pid": Pid, "cpusetsize": UInt, "mask": Ptr),
+        Syscall::SchedGetaffinity => sig!(Int, "pid": Pid, "cpusetsize": UInt, "mask": Ptr),
+        // Signals
+        Syscall::Kill => sig!(Errno, "pid": Pid, "sig": Signal),
+        Syscall::Tkill => sig!(Errno, "tid": Pid, "sig": Signal),
+        Syscall::Tgkill => sig!(Errno, "tgid": Pid, "tid": Pid, "sig": Signal),
+        Syscall::RtSigaction => sig!(
+            Errno,
+            "signum": Signal,
+            "act": SigActionPtr,
+            "oldact": SigActionPtr,
+            "sigsetsize": UInt,
+        ),
+        Syscall::RtSigprocmask => sig!(
+            Errno,
+            "how": SigProcMaskHow,
+            "set": SigSetPtr,
+            "oldset": SigSetPtr,
+            "sigsetsize": UInt,
+        ),
+        Syscall::RtSigpending => sig!(Errno, "set": SigSetPtr, "sigsetsize": UInt),
+        Syscall::RtSigsuspend => sig!(Errno, "mask": SigSetPtr, "sigsetsize": UInt),
+        Syscall::RtSigtimedwait => sig!(
+            Int,
+            "set": SigSetPtr,
+            "info": SigInfoPtr,
+            "timeout": TimeSpecPtr,
+            "sigsetsize": UInt,
+        ),
+        Syscall::RtSigqueueinfo => sig!(Errno, "tgid": Pid, "sig": Signal, "info": SigInfoPtr),
+        Syscall::RtTgsigqueueinfo => sig!(
+            Errno,
+            "tgid": Pid,
+            "tid": Pid,
+            "sig": Signal,
+            "info": SigInfoPtr,
+        ),
+        Syscall::RtSigreturn => sig!(Int),
+        Syscall::Sigaltstack => sig!(Errno, "ss": Ptr, "old_ss": Ptr),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Pause => sig!(Errno),
+        // Time
+        Syscall::Nanosleep => sig!(Errno, "req": TimeSpecPtr, "rem": TimeSpecPtr),
+        Syscall::ClockGettime => sig!(Errno, "clockid": ClockId, "tp": TimeSpecPtr),
+        Syscall::ClockSettime => sig!(Errno, "clockid": ClockId, "tp": TimeSpecPtr),
+        Syscall::ClockGetres => sig!(Errno, "clockid": ClockId, "res": TimeSpecPtr),
+        Syscall::ClockNanosleep => sig!(
+            Errno,
+            "clockid": ClockId,
+            "flags": Hex,
+            "request": TimeSpecPtr,
+            "remain": TimeSpecPtr,
+        ),
+        Syscall::Gettimeofday => sig!(Errno, "tv": TimeValPtr, "tz": Ptr),
+        Syscall::Settimeofday => sig!(Errno, "tv": TimeValPtr, "tz": Ptr),
+        Syscall::Getitimer => sig!(Errno, "which": TimerType, "curr_value": ITimerValPtr),
+        Syscall::Setitimer => sig!(
+            Errno,
+            "which": TimerType,
+            "new_value": ITimerValPtr,
+            "old_value": ITimerValPtr,
+        ),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Alarm => sig!(Int, "seconds": UInt),
+        #[cfg(feature = "arch-x86_64")]
+        Syscall::Time => sig!(Int, "tloc": Ptr),
+        // Sockets
+        Syscall::Socket => sig!(Fd, "domain": Domain, "type": SocketType, "protocol": Int),
+        Syscall::Socketpair => sig!(
+            Errno,
+            "domain": Domain,
+            "type": SocketType,
+            "protocol": Int,
+            "sv": Ptr,
+        ),
+        Syscall::Bind => sig!(Errno, "sockfd": Fd, "addr": SockAddrPtr, "addrlen": UInt),
+        Syscall::Connect => sig!(Errno, "sockfd": Fd, "addr": SockAddrPtr, "addrlen": UInt),
+        Syscall::Listen => sig!(Errno, "sockfd": Fd, "backlog": Int),
+        Syscall::Accept => sig!(Fd, "sockfd": Fd, "addr": SockAddrPtr, "addrlen": Ptr),
+        Syscall::Accept4 => sig!(
+            Fd,
+            "sockfd": Fd,
+            "addr": SockAddrPtr,
+            "addrlen": Ptr,
+            "flags": OpenFlags,
+        ),
+        Syscall::Getsockname => sig!(Errno, "sockfd": Fd, "addr": SockAddrPtr, "addrlen": Ptr),
+        Syscall::Getpeername => sig!(Errno, "sockfd": Fd, "addr": SockAddrPtr, "addrlen": Ptr),
+        Syscall::Sendto => sig!(
+            Size,
+            "sockfd": Fd,
+            "buf": Ptr,
+            "len": UInt,
+            "flags": Hex,
+            "dest_addr": SockAddrPtr,
+            "addrlen": UInt,
+        ),
+        Syscall::Recvfrom => sig!(
+            Size,
+            "sockfd": Fd,
+            "buf": Ptr,
+            "len": UInt,
+            "flags": Hex,
+            "src_addr": SockAddrPtr,
+            "addrlen": Ptr,
+        ),
+        Syscall::Sendmsg => sig!(Size, "sockfd": Fd, "msg": Ptr, "flags": Hex),
+        Syscall::Recvmsg => sig!(Size, "sockfd": Fd, "msg": Ptr, "flags": Hex),
+        Syscall::Setsockopt => sig!(
+            Errno,
+            "sockfd": Fd,
+            "level": SocketLevel,
+            "optname": Int,
+            "optval": Ptr,
+            "optlen": UInt,
+        ),
+        Syscall::Getsockopt => sig!(
+            Errno,
+            "sockfd": Fd,
+            "level": SocketLevel,
+            "optname": Int,
+            "optval": Ptr,
+            "optlen": Ptr,
+        ),
+        Syscall::Shutdown => sig!(Errno, "sockfd": Fd, "how": ShutdownHow),
+        _ => return None,
+    };
+    Some(signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_signature_is_well_formed() {
+        let mut described = 0;
+        for num in 0..1024 {
+            let Ok(syscall) = Syscall::try_from(num) else {
+                continue;
+            };
+            let Some(signature) = syscall_signature(syscall) else {
+                continue;
+            };
+            described += 1;
+            assert!(signature.args.len() <= 6, "{}", syscall);
+            for (i, arg) in signature.args.iter().enumerate() {
+                assert!(!arg.name.is_empty(), "{}", syscall);
+                assert!(
+                    signature.args[..i].iter().all(|a| a.name != arg.name),
+                    "{}",
+                    syscall
+                );
+            }
+        }
+        assert!(described > 150);
+    }
+
+    #[test]
+    fn common_syscalls_are_described() {
+        let common = [
+            Syscall::Read,
+            Syscall::Write,
+            Syscall::Openat,
+            Syscall::Close,
+            Syscall::Lseek,
+            Syscall::Ioctl,
+            Syscall::Fcntl,
+            Syscall::Mmap,
+            Syscall::Munmap,
+            Syscall::Brk,
+            Syscall::Clone,
+            Syscall::Execve,
+            Syscall::Exit,
+            Syscall::ExitGroup,
+            Syscall::Wait4,
+            Syscall::Futex,
+            Syscall::Getpid,
+            Syscall::RtSigaction,
+            Syscall::RtSigprocmask,
+            Syscall::RtSigreturn,
+        ];
+        for syscall in common {
+            assert!(syscall_signature(syscall).is_some(), "{}", syscall);
+        }
+    }
+
+    #[test]
+    fn known_signatures() {
+        let arity = |syscall| {
+            let signature = syscall_signature(syscall).unwrap();
+            (signature.args.len(), signature.ret)
+        };
+        assert_eq!(arity(Syscall::Read), (3, RetKind::Size));
+        assert_eq!(arity(Syscall::Openat), (4, RetKind::Fd));
+        assert_eq!(arity(Syscall::Close), (1, RetKind::Errno));
+        assert_eq!(arity(Syscall::Mmap), (6, RetKind::Addr));
+        assert_eq!(arity(Syscall::Futex), (6, RetKind::Int));
+        assert_eq!(arity(Syscall::Getpid), (0, RetKind::Pid));
+        assert_eq!(arity(Syscall::ExitGroup), (1, RetKind::NoReturn));
+        let openat = syscall_signature(Syscall::Openat).unwrap();
+        assert_eq!(
+            openat.args[2],
+            SyscallArg {
+                name: "flags",
+                kind: ArgKind::OpenFlags
+            }
+        );
+    }
+}
@@ -0,0 +1,661 @@
+//! strace-like rendering of a system call and its raw arguments.
+
+use core::fmt::{Display, Formatter, Result};
+
+use super::{syscall_signature, ArgKind, Syscall};
+use crate::{
+    epoll::{EpollCtlOp, EventFdFlags},
+    io::{
+        FaccessatFlags, FaccessatMode, LinkFlags, MMapFlags, MountFlags, OpenFlags, ProtFlags,
+        Renameat2Flags, StatFlags, StatxMask, UnlinkatFlags, Whence, MAP_HUGE_MASK, MAP_HUGE_SHIFT,
+        MMAP_TYPE_MASK,
+    },
+    ioctl::{IoctlCmd, _IOC_DIR, _IOC_NONE, _IOC_NR, _IOC_READ, _IOC_SIZE, _IOC_TYPE, _IOC_WRITE},
+    ipc::{FutexOp, ShmAtFlags, ShmCtlCmd, ShmGetFlags},
+    net::{Domain, ShutdownFlag, SocketLevel, SocketType, SOCKET_TYPE_MASK},
+    signal::{SigProcMaskHow, SignalNumber},
+    sys::{PrctlOp, RusageFlag, SyslogAction},
+    task::{CloneFlags, WaitOptions},
+    time::{ClockId, TimerFdFlags, TimerType},
+    PrLimitResType,
+};
+
+/// `AT_FDCWD` as seen in a `dirfd` argument.
+const AT_FDCWD: i32 = -100;
+
+/// A system call number and its six raw arguments, displayed like strace does,
+/// e.g. `openat(AT_FDCWD, 0x1000, O_RDONLY|O_CLOEXEC, 0o644)`.
+///
+/// Pointers are printed as addresses, nothing is read from user memory.
+/// Formatting does not allocate, so it can be used anywhere a `write!` can.
+#[derive(Debug, Clone, Copy)]
+pub struct SyscallTrace {
+    id: usize,
+    args: [usize; 6],
+}
+
+impl SyscallTrace {
+    pub const fn new(id: usize, args: [usize; 6]) -> Self {
+        Self { id, args }
+    }
+}
+
+impl Display for SyscallTrace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let syscall = match Syscall::try_from(self.id) {
+            Ok(syscall) => syscall,
+            Err(_) => {
+                write!(f, "syscall_{}", self.id)?;
+                return write_raw_args(f, &self.args);
+            }
+        };
+        f.write_str(syscall.name())?;
+        let signature = match syscall_signature(syscall) {
+            Some(signature) => signature,
+            None => return write_raw_args(f, &self.args),
+        };
+        f.write_str("(")?;
+        for (i, (arg, raw)) in signature.args.iter().zip(self.args).enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            write_arg(f, arg.kind, raw)?;
+        }
+        f.write_str(")")
+    }
+}
+
+fn write_raw_args(f: &mut Formatter<'_>, args: &[usize; 6]) -> Result {
+    write!(
+        f,
+        "({:#x}, {:#x}, {:#x}, {:#x}, {:#x}, {:#x})",
+        args[0], args[1], args[2], args[3], args[4], args[5]
+    )
+}
+
+fn write_arg(f: &mut Formatter<'_>, kind: ArgKind, raw: usize) -> Result {
+    match kind {
+        ArgKind::Int => write!(f, "{}", raw as isize),
+        ArgKind::UInt => write!(f, "{}", raw),
+        ArgKind::Hex => write!(f, "{:#x}", raw),
+        ArgKind::Fd | ArgKind::Pid => write!(f, "{}", raw as i32),
+        ArgKind::DirFd if raw as i32 == AT_FDCWD => f.write_str("AT_FDCWD"),
+        ArgKind::DirFd => write!(f, "{}", raw as i32),
+        ArgKind::Mode => write!(f, "{:#o}", raw),
+        ArgKind::OpenFlags => write_open_flags(f, raw),
+        ArgKind::StatFlags => write_flags(f, raw, STAT_FLAGS),
+        ArgKind::StatxMask => write_flags(f, raw, STATX_MASK),
+        ArgKind::UnlinkatFlags => write_flags(f, raw, UNLINKAT_FLAGS),
+        ArgKind::LinkFlags => write_flags(f, raw, LINK_FLAGS),
+        ArgKind::FaccessatMode if raw == 0 => f.write_str("F_OK"),
+        ArgKind::FaccessatMode => write_flags(f, raw, FACCESSAT_MODE),
+        ArgKind::FaccessatFlags => write_flags(f, raw, FACCESSAT_FLAGS),
+        ArgKind::Renameat2Flags => write_flags(f, raw, RENAMEAT2_FLAGS),
+        ArgKind::MountFlags => write_flags(f, raw, MOUNT_FLAGS),
+        ArgKind::ProtFlags if raw == 0 => f.write_str("PROT_NONE"),
+        ArgKind::ProtFlags => write_flags(f, raw, PROT_FLAGS),
+        ArgKind::MMapFlags => write_mmap_flags(f, raw),
+        ArgKind::CloneFlags => write_clone_flags(f, raw),
+        ArgKind::WaitOptions => write_flags(f, raw, WAIT_OPTIONS),
+        ArgKind::EventFdFlags => write_flags(f, raw, EVENTFD_FLAGS),
+        ArgKind::TimerFdFlags => write_flags(f, raw, TIMERFD_FLAGS),
+        ArgKind::FutexOp => write_futex_op(f, raw),
+        ArgKind::ClockId => write_clock_id(f, raw),
+        ArgKind::TimerType => write_timer_type(f, raw),
+        ArgKind::Signal => write_signal(f, raw),
+        ArgKind::SigProcMaskHow => write_sigprocmask_how(f, raw),
+        ArgKind::Whence => write_whence(f, raw),
+        ArgKind::FcntlCmd => write_fcntl_cmd(f, raw),
+        ArgKind::IoctlCmd => write_ioctl_cmd(f, raw),
+        ArgKind::EpollCtlOp => write_epoll_ctl_op(f, raw),
+        ArgKind::PrctlOp => match PrctlOp::try_from(raw as u32) {
+            Ok(op) => write!(f, "{:?}", op),
+            Err(_) => write!(f, "{}", raw),
+        },
+        ArgKind::SyslogAction => write_syslog_action(f, raw),
+        ArgKind::RusageWho => write_rusage_who(f, raw),
+        ArgKind::Resource => write_resource(f, raw),
+        ArgKind::ShmGetFlags => write_shmget_flags(f, raw),
+        ArgKind::ShmAtFlags => write_flags(f, raw, SHMAT_FLAGS),
+        ArgKind::ShmCtlCmd => write_shmctl_cmd(f, raw),
+        ArgKind::Domain => match Domain::try_from(raw) {
+            Ok(domain) => write!(f, "{:?}", domain),
+            Err(_) => write!(f, "{}", raw),
+        },
+        ArgKind::SocketType => write_socket_type(f, raw),
+        ArgKind::SocketLevel => write_socket_level(f, raw),
+        ArgKind::ShutdownHow => write_shutdown_how(f, raw),
+        ArgKind::Ptr
+        | ArgKind::Path
+        | ArgKind::TimeSpecPtr
+        | ArgKind::TimeValPtr
+        | ArgKind::ITimerValPtr
+        | ArgKind::ITimeSpecPtr
+        | ArgKind::SigSetPtr
+        | ArgKind::SigActionPtr
+        | ArgKind::SigInfoPtr
+        | ArgKind::StatPtr
+        | ArgKind::StatxPtr
+        | ArgKind::OpenHowPtr
+        | ArgKind::StatFsPtr
+        | ArgKind::IoVecPtr
+        | ArgKind::PollFdPtr
+        | ArgKind::EpollEventPtr
+        | ArgKind::RLimitPtr
+        | ArgKind::RusagePtr
+        | ArgKind::TimesPtr
+        | ArgKind::SysinfoPtr
+        | ArgKind::SockAddrPtr => write_ptr(f, raw),
+    }
+}
+
+fn write_ptr(f: &mut Formatter<'_>, raw: usize) -> Result {
+    if raw == 0 {
+        f.write_str("NULL")
+    } else {
+        write!(f, "{:#x}", raw)
+    }
+}
+
+/// Writes the names of the flags in `names` that are set in `bits`, joined by
+/// `|`. Bits without a name are written as one hexadecimal number at the end.
+fn write_flags(f: &mut Formatter<'_>, bits: usize, names: &[(usize, &str)]) -> Result {
+    if bits == 0 {
+        return f.write_str("0");
+    }
+    write_flags_after(f, bits, names, false)
+}
+
+/// Like [`write_flags`], but continues a list that may already have entries.
+fn write_flags_after(
+    f: &mut Formatter<'_>,
+    bits: usize,
+    names: &[(usize, &str)],
+    mut written: bool,
+) -> Result {
+    let mut rest = bits;
+    for &(flag, name) in names {
+        if flag != 0 && rest & flag == flag {
+            if written {
+                f.write_str("|")?;
+            }
+            f.write_str(name)?;
+            written = true;
+            rest &= !flag;
+        }
+    }
+    if rest != 0 {
+        if written {
+            f.write_str("|")?;
+        }
+        write!(f, "{:#x}", rest)?;
+    }
+    Ok(())
+}
+
+fn write_open_flags(f: &mut Formatter<'_>, raw: usize) -> Result {
+    let access = raw & OpenFlags::O_ACCMODE.bits();
+    match access {
+        0 => f.write_str("O_RDONLY")?,
+        1 => f.write_str("O_WRONLY")?,
+        2 => f.write_str("O_RDWR")?,
+        _ => write!(f, "{:#x}", access)?,
+    }
+    write_flags_after(f, raw & !OpenFlags::O_ACCMODE.bits(), OPEN_FLAGS, true)
+}
+
+fn write_mmap_flags(f: &mut Formatter<'_>, raw: usize) -> Result {
+    let ty = raw & MMAP_TYPE_MASK as usize;
+    let huge = (raw >> MAP_HUGE_SHIFT) & MAP_HUGE_MASK as usize;
+    let rest = raw & !(MMAP_TYPE_MASK as usize) & !((MAP_HUGE_MASK as usize) << MAP_HUGE_SHIFT);
+    match ty {
+        0 if rest == 0 && huge != 0 => return write!(f, "{}<<MAP_HUGE_SHIFT", huge),
+        0 => write_flags(f, rest, MMAP_FLAGS)?,
+        1 => f.write_str("MAP_SHARED")?,
+        2 => f.write_str("MAP_PRIVATE")?,
+        3 => f.write_str("MAP_SHARED_VALIDATE")?,
+        _ => write!(f, "{:#x}", ty)?,
+    }
+    if ty != 0 {
+        write_flags_after(f, rest, MMAP_FLAGS, true)?;
+    }
+    if huge != 0 {
+        write!(f, "|{}<<MAP_HUGE_SHIFT", huge)?;
+    }
+    Ok(())
+}
+
+/// clone(2) keeps the exit signal in the low byte of the flags.
+fn write_clone_flags(f: &mut Formatter<'_>, raw: usize) -> Result {
+    let signal = raw & 0xff;
+    let flags = raw & !0xff;
+    if flags == 0 && signal == 0 {
+        return f.write_str("0");
+    }
+    write_flags_after(f, flags, CLONE_FLAGS, false)?;
+    if signal != 0 {
+        if flags != 0 {
+            f.write_str("|")?;
+        }
+        write_signal(f, signal)?;
+    }
+    Ok(())
+}
+
+fn write_signal(f: &mut Formatter<'_>, raw: usize) -> Result {
+    match u8::try_from(raw).map(SignalNumber::try_from) {
+        Ok(Ok(signal)) if signal != SignalNumber::ERR => write!(f, "{:?}", signal),
+        _ => write!(f, "{}", raw as i32),
+    }
+}
+
+fn write_futex_op(f: &mut Formatter<'_>, raw: usize) -> Result {
+    const FUTEX_PRIVATE_FLAG: usize = 128;
+    const FUTEX_CLOCK_REALTIME: usize = 256;
+    let cmd = raw & !(FUTEX_PRIVATE_FLAG | FUTEX_CLOCK_REALTIME);
+    let name = match FutexOp::try_from(cmd as u32) {
+        Ok(FutexOp::FutexWait) => "FUTEX_WAIT",
+        Ok(FutexOp::FutexWake) => "FUTEX_WAKE",
+        Ok(FutexOp::FutexFd) => "FUTEX_FD",
+        Ok(FutexOp::FutexRequeue) => "FUTEX_REQUEUE",
+        Ok(FutexOp::FutexCmpRequeue) => "FUTEX_CMP_REQUEUE",
+        Ok(FutexOp::FutexWakeOp) => "FUTEX_WAKE_OP",
+        Ok(FutexOp::FutexLockPi) => "FUTEX_LOCK_PI",
+        Ok(FutexOp::FutexUnlockPi) => "FUTEX_UNLOCK_PI",
+        Ok(FutexOp::FutexTrylockPi) => "FUTEX_TRYLOCK_PI",
+        Ok(FutexOp::FutexWaitBitset) => "FUTEX_WAIT_BITSET",
+        Ok(FutexOp::FutexWakeBitset) => "FUTEX_WAKE_BITSET",
+        Ok(FutexOp::FutexWaitRequeuePi) => "FUTEX_WAIT_REQUEUE_PI",
+        Ok(FutexOp::FutexCmpRequeuePi) => "FUTEX_CMP_REQUEUE_PI",
+        _ => return write!(f, "{:#x}", raw),
+    };
+    f.write_str(name)?;
+    if raw & FUTEX_PRIVATE_FLAG != 0 {
+        f.write_str("_PRIVATE")?;
+    }
+    if raw & FUTEX_CLOCK_REALTIME != 0 {
+        f.write_str("|FUTEX_CLOCK_REALTIME")?;
+    }
+    Ok(())
+}
+
+fn write_clock_id(f: &mut Formatter<'_>, raw: usize) -> Result {
+    let name = match ClockId::try_from(raw) {
+        Ok(ClockId::Realtime) => "CLOCK_REALTIME",
+        Ok(ClockId::Monotonic) => "CLOCK_MONOTONIC",
+        Ok(ClockId::ProcessCputimeId) => "CLOCK_PROCESS_CPUTIME_ID",
+        Ok(ClockId::ThreadCputimeId) => "CLOCK_THREAD_CPUTIME_ID",
+        Ok(ClockId::MonotonicRaw) => "CLOCK_MONOTONIC_RAW",
+        Ok(ClockId::RealtimeCoarse) => "CLOCK_REALTIME_COARSE",
+        Ok(ClockId::MonotonicCoarse) => "CLOCK_MONOTONIC_COARSE",
+        Ok(ClockId::Boottime) => "CLOCK_BOOTTIME",
+        Ok(ClockId::RealtimeAlarm) => "CLOCK_REALTIME_ALARM",
+        Ok(ClockId::BoottimeAlarm) => "CLOCK_BOOTTIME_ALARM",
+        Ok(ClockId::Tai) => "CLOCK_TAI",
+        // dynamic clocks such as the CPU clock of another process are negative
+        Err(_) => return write!(f, "{}", raw as i32),
+    };
+    f.write_str(name)
+}
+
+fn write_timer_type(f: &mut Formatter<'_>, raw: usize) -> Result {
+    match TimerType::try_from(raw) {
+        Ok(TimerType::REAL) => f.write_str("ITIMER_REAL"),
+        Ok(TimerType::VIRTUAL) => f.write_str("ITIMER_VIRTUAL"),
+        Ok(TimerType::PROF) => f.write_str("ITIMER_PROF"),
+        _ => write!(f, "{}", raw as i32),
+    }
+}
+
+fn write_sigprocmask_how(f: &mut Formatter<'_>, raw: usize) -> Result {
+    match SigProcMaskHow::try_from(raw) {
+        Ok(SigProcMaskHow::SigBlock) => f.write_str("SIG_BLOCK"),
+        Ok(SigProcMaskHow::SigUnblock) => f.write_str("SIG_UNBLOCK"),
+        Ok(SigProcMaskHow::SigSetMask) => f.write_str("SIG_SETMASK"),
+        Err(_) => write!(f, "{}", raw as i32),
+    }
+}
+
+fn write_whence(f: &mut Formatter<'_>, raw: usize) -> Result {
+    match Whence::try_from(raw) {
+        Ok(Whence::SeekSet) => f.write_str("SEEK_SET"),
+        Ok(Whence::SeekCur) => f.write_str("SEEK_CUR"),
+        Ok(Whence::SeekEnd) => f.write_str("SEEK_END"),
+        Ok(Whence::SeekData) => f.write_str("SEEK_DATA"),
+        Ok(Whence::SeekHole) => f.write_str("SEEK_HOLE"),
+        Err(_) => write!(f, "{}", raw as i32),
+    }
+}
+
+fn write_fcntl_cmd(f: &mut Formatter<'_>, raw: usize) -> Result {
+    match crate::io::Fcntl64Cmd::try_from(raw as u32) {
+        Ok(cmd) => f.write_str(cmd.name()),
+        Err(_) => write!(f, "{}", raw as i32),
+    }
+}
+
+fn write_ioctl_cmd(f: &mut Formatter<'_>, raw: usize) -> Result {
+    match IoctlCmd::from(raw as u32) {
+        IoctlCmd::Tty(cmd) => write!(f, "{:?}", cmd),
+        IoctlCmd::Rtc(cmd) => write!(f, "{:?}", cmd),
+        IoctlCmd::Block(cmd) => write!(f, "{:?}", cmd),
+        IoctlCmd::Framebuffer(cmd) => write!(f, "{:?}", cmd),
+        IoctlCmd::Unknown(cmd) if _IOC_DIR(cmd) != _IOC_NONE || _IOC_SIZE(cmd) != 0 => {
+            let dir = match _IOC_DIR(cmd) {
+                _IOC_NONE => "_IOC_NONE",
+                _IOC_READ => "_IOC_READ",
+                _IOC_WRITE => "_IOC_WRITE",
+                _ => "_IOC_READ|_IOC_WRITE",
+            };
+            write!(
+                f,
+                "_IOC({}, {:#x}, {:#x}, {:#x})",
+                dir,
+                _IOC_TYPE(cmd),
+                _IOC_NR(cmd),
+                _IOC_SIZE(cmd)
+            )
+        }
+        IoctlCmd::Unknown(_) => write!(f, "{:#x}", raw),
+    }
+}
+
+fn write_epoll_ctl_op(f: &mut Formatter<'_>, raw: usize) -> Result {
+    match EpollCtlOp::try_from(raw as u32) {
+        Ok(EpollCtlOp::EpollCtlAdd) => f.write_str("EPOLL_CTL_ADD"),
+        Ok(EpollCtlOp::EpollCtlDel) => f.write_str("EPOLL_CTL_DEL"),
+        Ok(EpollCtlOp::EpollCtlMod) => f.write_str("EPOLL_CTL_MOD"),
+        Err(_) => write!(f, "{}", raw as i32),
+    }
+}
+
+fn write_syslog_action(f: &mut Formatter<'_>, raw: usize) -> Result {
+    let name = match SyslogAction::try_from(raw as u32) {
+        Ok(SyslogAction::CLOSE) => "SYSLOG_ACTION_CLOSE",
+        Ok(SyslogAction::OPEN) => "SYSLOG_ACTION_OPEN",
+        Ok(SyslogAction::READ) => "SYSLOG_ACTION_READ",
+        Ok(SyslogAction::ReadAll) => "SYSLOG_ACTION_READ_ALL",
+        Ok(SyslogAction::ReadClear) => "SYSLOG_ACTION_READ_CLEAR",
+        Ok(SyslogAction::CLEAR) => "SYSLOG_ACTION_CLEAR",
+        Ok(SyslogAction::ConsoleOff) => "SYSLOG_ACTION_CONSOLE_OFF",
+        Ok(SyslogAction::ConsoleOn) => "SYSLOG_ACTION_CONSOLE_ON",
+        Ok(SyslogAction::ConsoleLevel) => "SYSLOG_ACTION_CONSOLE_LEVEL",
+        Ok(SyslogAction::SizeUnread) => "SYSLOG_ACTION_SIZE_UNREAD",
+        Ok(SyslogAction::SizeBuffer) => "SYSLOG_ACTION_SIZE_BUFFER",
+        // 不是 Linux 定义的操作
+        Ok(SyslogAction::Unknown) | Err(_) => return write!(f, "{}", raw as i32),
+    };
+    f.write_str(name)
+}
+
+fn write_rusage_who(f: &mut Formatter<'_>, raw: usize) -> Result {
+    match RusageFlag::try_from(raw as isize) {
+        Ok(RusageFlag::RusageSelf) => f.write_str("RUSAGE_SELF"),
+        Ok(RusageFlag::RusageChildren) => f.write_str("RUSAGE_CHILDREN"),
+        Ok(RusageFlag::RusageThread) => f.write_str("RUSAGE_THREAD"),
+        Err(_) => write!(f, "{}", raw as i32),
+    }
+}
+
+fn write_resource(f: &mut Formatter<'_>, raw: usize) -> Result {
+    match PrLimitResType::try_from(raw) {
+        Ok(resource) => f.write_str(resource.name()),
+        Err(_) => write!(f, "{}", raw),
+    }
+}
+
+fn write_shmctl_cmd(f: &mut Formatter<'_>, raw: usize) -> Result {
+    let name = match ShmCtlCmd::try_from(raw as u32) {
+        Ok(ShmCtlCmd::IpcRmid) => "IPC_RMID",
+        Ok(ShmCtlCmd::IpcSet) => "IPC_SET",
+        Ok(ShmCtlCmd::IpcStat) => "IPC_STAT",
+        Ok(ShmCtlCmd::ShmLock) => "SHM_LOCK",
+        Ok(ShmCtlCmd::ShmUnlock) => "SHM_UNLOCK",
+        Ok(ShmCtlCmd::ShmStat) => "SHM_STAT",
+        Ok(ShmCtlCmd::ShmInfo) => "SHM_INFO",
+        Ok(ShmCtlCmd::ShmStatAny) => "SHM_STAT_ANY",
+        Err(_) => return write!(f, "{}", raw as i32),
+    };
+    f.write_str(name)
+}
+
+/// The low nine bits of the shmget flags are permissions, like an open mode.
+fn write_shmget_flags(f: &mut Formatter<'_>, raw: usize) -> Result {
+    let mode = raw & 0o777;
+    let flags = raw & !0o777;
+    if flags == 0 {
+        return write!(f, "{:#o}", mode);
+    }
+    write_flags_after(f, flags, SHMGET_FLAGS, false)?;
+    if mode != 0 {
+        write!(f, "|{:#o}", mode)?;
+    }
+    Ok(())
+}
+
+fn write_socket_type(f: &mut Formatter<'_>, raw: usize) -> Result {
+    let ty = raw & SOCKET_TYPE_MASK as usize;
+    match SocketType::try_from(ty) {
+        Ok(ty) => write!(f, "{:?}", ty)?,
+        Err(_) => write!(f, "{}", ty)?,
+    }
+    write_flags_after(
+        f,
+        raw & !(SOCKET_TYPE_MASK as usize),
+        SOCKET_TYPE_FLAGS,
+        true,
+    )
+}
+
+fn write_socket_level(f: &mut Formatter<'_>, raw: usize) -> Result {
+    match SocketLevel::try_from(raw) {
+        Ok(SocketLevel::Ip) => f.write_str("SOL_IP"),
+        Ok(SocketLevel::Socket) => f.write_str("SOL_SOCKET"),
+        Ok(SocketLevel::Tcp) => f.write_str("SOL_TCP"),
+        Err(_) => write!(f, "{}", raw as i32),
+    }
+}
+
+fn write_shutdown_how(f: &mut Formatter<'_>, raw: usize) -> Result {
+    match ShutdownFlag::try_from(raw) {
+        Ok(ShutdownFlag::SHUTRD) => f.write_str("SHUT_RD"),
+        Ok(ShutdownFlag::SHUTWR) => f.write_str("SHUT_WR"),
+        Ok(ShutdownFlag::SHUTRDWR) => f.write_str("SHUT_RDWR"),
+        Err(_) => write!(f, "{}", raw as i32),
+    }
+}
+
+// Flags that contain other flags come first, so that e.g. `O_SYNC` is not
+// printed as `O_DSYNC|0x100000`.
+
+macro_rules! flag_names {
+    ($ty:ident; $($flag:ident),* $(,)?) => {
+        &[$(($ty::$flag.bits() as usize, stringify!($flag))),*]
+    };
+}
+
+const OPEN_FLAGS: &[(usize, &str)] = flag_names!(
+    OpenFlags;
+    O_CREAT, O_EXCL, O_NOCTTY, O_TRUNC, O_APPEND, O_NONBLOCK, O_SYNC, O_DSYNC, O_ASYNC,
+    O_DIRECT, O_LARGEFILE, O_TMPFILE, O_DIRECTORY, O_NOFOLLOW, O_NOATIME, O_CLOEXEC, O_PATH,
+);
+
+const STAT_FLAGS: &[(usize, &str)] = flag_names!(
+    StatFlags;
+    AT_SYMLINK_NOFOLLOW, AT_NO_AUTOMOUNT, AT_EMPTY_PATH, AT_STATX_FORCE_SYNC, AT_STATX_DONT_SYNC,
+);
+
+const STATX_MASK: &[(usize, &str)] = flag_names!(
+    StatxMask;
+    STATX_BASIC_STATS, STATX_TYPE, STATX_MODE, STATX_NLINK, STATX_UID, STATX_GID, STATX_ATIME,
+    STATX_MTIME, STATX_CTIME, STATX_INO, STATX_SIZE, STATX_BLOCKS, STATX_BTIME, STATX_MNT_ID,
+    STATX_DIOALIGN,
+);
+
+const UNLINKAT_FLAGS: &[(usize, &str)] = flag_names!(UnlinkatFlags; AT_REMOVEDIR);
+
+const LINK_FLAGS: &[(usize, &str)] = flag_names!(LinkFlags; AT_SYMLINK_FOLLOW, AT_EMPTY_PATH);
+
+const FACCESSAT_MODE: &[(usize, &str)] = flag_names!(FaccessatMode; R_OK, W_OK, X_OK);
+
+const FACCESSAT_FLAGS: &[(usize, &str)] = flag_names!(
+    FaccessatFlags;
+    AT_SYMLINK_NOFOLLOW, AT_EACCESS,
+);
+
+const RENAMEAT2_FLAGS: &[(usize, &str)] = flag_names!(
+    Renameat2Flags;
+    RENAME_NOREPLACE, RENAME_EXCHANGE, RENAME_WHITEOUT,
+);
+
+const MOUNT_FLAGS: &[(usize, &str)] = flag_names!(
+    MountFlags;
+    MS_RDONLY, MS_NOSUID, MS_NODEV, MS_NOEXEC, MS_SYNCHRONOUS, MS_REMOUNT, MS_MANDLOCK,
+    MS_DIRSYNC, MS_NOSYMFOLLOW, MS_NOATIME, MS_NODEIRATIME, MS_BIND, MS_MOVE, MS_REC, MS_SILENT,
+    MS_POSIXACL, MS_UNBINDABLE, MS_PRIVATE, MS_SLAVE, MS_SHARED, MS_RELATIME, MS_KERNMOUNT,
+    MS_I_VERSION, MS_STRICTATIME, MS_LAZYTIME,
+);
+
+const PROT_FLAGS: &[(usize, &str)] = flag_names!(
+    ProtFlags;
+    PROT_READ, PROT_WRITE, PROT_EXEC, PROT_SEM, PROT_GROWSDOWN, PROT_GROWSUP
+);
+
+const MMAP_FLAGS: &[(usize, &str)] = flag_names!(
+    MMapFlags;
+    MAP_FIXED, MAP_ANONYMOUS, MAP_32BIT, MAP_GROWSDOWN, MAP_DENYWRITE, MAP_EXECUTABLE,
+    MAP_LOCKED, MAP_NORESERVE, MAP_POPULATE, MAP_NONBLOCK, MAP_STACK, MAP_HUGETLB, MAP_SYNC,
+    MAP_FIXED_NOREPLACE,
+);
+
+const CLONE_FLAGS: &[(usize, &str)] = flag_names!(
+    CloneFlags;
+    CLONE_VM, CLONE_FS, CLONE_FILES, CLONE_SIGHAND, CLONE_PIDFD, CLONE_PTRACE, CLONE_VFORK,
+    CLONE_PARENT, CLONE_THREAD, CLONE_NEWNS, CLONE_SYSVSEM, CLONE_SETTLS, CLONE_PARENT_SETTID,
+    CLONE_CHILD_CLEARTID, CLONE_DETACHED, CLONE_UNTRACED, CLONE_CHILD_SETTID, CLONE_NEWCGROUP,
+    CLONE_NEWUTS, CLONE_NEWIPC, CLONE_NEWUSER, CLONE_NEWPID, CLONE_NEWNET, CLONE_IO,
+);
+
+const WAIT_OPTIONS: &[(usize, &str)] = flag_names!(
+    WaitOptions;
+    WNOHANG, WUNTRACED, WEXITED, WCONTINUED, WNOWAIT,
+);
+
+const EVENTFD_FLAGS: &[(usize, &str)] = flag_names!(
+    EventFdFlags;
+    EFD_SEMAPHORE, EFD_CLOEXEC, EFD_NONBLOCK,
+);
+
+const TIMERFD_FLAGS: &[(usize, &str)] = flag_names!(
+    TimerFdFlags;
+    TFD_TIMER_ABSTIME, TFD_TIMER_CANCEL_ON_SET,
+);
+
+const SHMGET_FLAGS: &[(usize, &str)] = flag_names!(
+    ShmGetFlags;
+    IPC_CREAT, IPC_EXCL, SHM_HUGETLB, SHM_NORESERVE,
+);
+
+const SHMAT_FLAGS: &[(usize, &str)] = flag_names!(
+    ShmAtFlags;
+    SHM_RDONLY, SHM_RND, SHM_REMAP, SHM_EXEC,
+);
+
+const SOCKET_TYPE_FLAGS: &[(usize, &str)] = &[
+    (SocketType::SOCK_NONBLOCK as usize, "SOCK_NONBLOCK"),
+    (SocketType::SOCK_CLOEXEC as usize, "SOCK_CLOEXEC"),
+];
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String};
+
+    use super::*;
+    use crate::syscall::{
+        SYSCALL_CLOSE, SYSCALL_FUTEX, SYSCALL_MMAP, SYSCALL_OPENAT, SYSCALL_SYSLOG,
+    };
+
+    fn trace(id: usize, args: [usize; 6]) -> String {
+        format!("{}", SyscallTrace::new(id, args))
+    }
+
+    #[test]
+    fn openat() {
+        let flags = (OpenFlags::O_RDONLY | OpenFlags::O_CLOEXEC).bits();
+        assert_eq!(
+            trace(
+                SYSCALL_OPENAT,
+                [-100isize as usize, 0x1000, flags, 0o644, 0, 0]
+            ),
+            "openat(AT_FDCWD, 0x1000, O_RDONLY|O_CLOEXEC, 0o644)"
+        );
+        assert_eq!(
+            trace(SYSCALL_OPENAT, [3, 0, 0o101, 0, 0, 0]),
+            "openat(3, NULL, O_WRONLY|O_CREAT, 0o0)"
+        );
+        assert_eq!(
+            trace(SYSCALL_CLOSE, [-1isize as usize, 7, 7, 7, 7, 7]),
+            "close(-1)"
+        );
+    }
+
+    #[test]
+    fn mmap() {
+        assert_eq!(
+            trace(SYSCALL_MMAP, [0, 4096, 3, 0x22, -1isize as usize, 0]),
+            "mmap(NULL, 4096, PROT_READ|PROT_WRITE, MAP_PRIVATE|MAP_ANONYMOUS, -1, 0)"
+        );
+        assert_eq!(
+            trace(SYSCALL_MMAP, [0x2000, 8192, 0, 0x11, 5, 0x1000]),
+            "mmap(0x2000, 8192, PROT_NONE, MAP_SHARED|MAP_FIXED, 5, 4096)"
+        );
+        let huge = 0x40022 | 21 << MAP_HUGE_SHIFT;
+        assert_eq!(
+            trace(SYSCALL_MMAP, [0, 4096, 1, huge, -1isize as usize, 0]),
+            "mmap(NULL, 4096, PROT_READ, MAP_PRIVATE|MAP_ANONYMOUS|MAP_HUGETLB|21<<MAP_HUGE_SHIFT, -1, 0)"
+        );
+    }
+
+    #[test]
+    fn futex() {
+        assert_eq!(
+            trace(SYSCALL_FUTEX, [0x1000, 128, 1, 0, 0, 0]),
+            "futex(0x1000, FUTEX_WAIT_PRIVATE, 1, NULL, NULL, 0)"
+        );
+        assert_eq!(
+            trace(SYSCALL_FUTEX, [0x1000, 9 | 128 | 256, 0, 0x2000, 0, usize::MAX]),
+            format!(
+                "futex(0x1000, FUTEX_WAIT_BITSET_PRIVATE|FUTEX_CLOCK_REALTIME, 0, 0x2000, NULL, {})",
+                usize::MAX
+            )
+        );
+        assert_eq!(
+            trace(SYSCALL_FUTEX, [0x1000, 99, 0, 0, 0, 0]),
+            "futex(0x1000, 0x63, 0, NULL, NULL, 0)"
+        );
+    }
+
+    #[test]
+    fn syslog() {
+        assert_eq!(
+            trace(SYSCALL_SYSLOG, [3, 0, 0, 0, 0, 0]),
+            "syslog(SYSLOG_ACTION_READ_ALL, NULL, 0)"
+        );
+        assert_eq!(
+            trace(SYSCALL_SYSLOG, [10, 0, 0, 0, 0, 0]),
+            "syslog(SYSLOG_ACTION_SIZE_BUFFER, NULL, 0)"
+        );
+        assert_eq!(
+            trace(SYSCALL_SYSLOG, [11, 0, 0, 0, 0, 0]),
+            "syslog(11, NULL, 0)"
+        );
+    }
+
+    #[test]
+    fn unknown_syscall() {
+        assert_eq!(
+            trace(1000, [1, 2, 3, 4, 5, 6]),
+            "syscall_1000(0x1, 0x2, 0x3, 0x4, 0x5, 0x6)"
+        );
+    }
+}
@@ -0,0 +1,365 @@
+//! System call numbers from `arch/x86/entry/syscalls/syscall_64.tbl`.
+
+syscall_table! {
+    0 => SYSCALL_READ, Read, "read";
+    1 => SYSCALL_WRITE, Write, "write";
+    2 => SYSCALL_OPEN, Open, "open";
+    3 => SYSCALL_CLOSE, Close, "close";
+    4 => SYSCALL_STAT, Stat, "stat";
+    5 => SYSCALL_FSTAT, Fstat, "fstat";
+    6 => SYSCALL_LSTAT, Lstat, "lstat";
+    7 => SYSCALL_POLL, Poll, "poll";
+    8 => SYSCALL_LSEEK, Lseek, "lseek";
+    9 => SYSCALL_MMAP, Mmap, "mmap";
+    10 => SYSCALL_MPROTECT, Mprotect, "mprotect";
+    11 => SYSCALL_MUNMAP, Munmap, "munmap";
+    12 => SYSCALL_BRK, Brk, "brk";
+    13 => SYSCALL_SIGACTION, RtSigaction, "rt_sigaction";
+    14 => SYSCALL_SIGPROCMASK, RtSigprocmask, "rt_sigprocmask";
+    15 => SYSCALL_SIGRETURN, RtSigreturn, "rt_sigreturn";
+    16 => SYSCALL_IOCTL, Ioctl, "ioctl";
+    17 => SYSCALL_PREAD, Pread64, "pread64";
+    18 => SYSCALL_PWRITE, Pwrite64, "pwrite64";
+    19 => SYSCALL_READV, Readv, "readv";
+    20 => SYSCALL_WRITEV, Writev, "writev";
+    21 => SYSCALL_ACCESS, Access, "access";
+    22 => SYSCALL_PIPE, Pipe, "pipe";
+    23 => SYSCALL_SELECT, Select, "select";
+    24 => SYSCALL_YIELD, SchedYield, "sched_yield";
+    25 => SYSCALL_MREMAP, Mremap, "mremap";
+    26 => SYSCALL_MSYNC, Msync, "msync";
+    27 => SYSCALL_MINCORE, Mincore, "mincore";
+    28 => SYSCALL_MADVISE, Madvise, "madvise";
+    29 => SYSCALL_SHMGET, Shmget, "shmget";
+    30 => SYSCALL_SHAMAT, Shmat, "shmat";
+    31 => SYSCALL_SHAMCTL, Shmctl, "shmctl";
+    32 => SYSCALL_DUP, Dup, "dup";
+    33 => SYSCALL_DUP2, Dup2, "dup2";
+    34 => SYSCALL_PAUSE, Pause, "pause";
+    35 => SYSCALL_NANOSLEEP, Nanosleep, "nanosleep";
+    36 => SYSCALL_GETITIMER, Getitimer, "getitimer";
+    37 => SYSCALL_ALARM, Alarm, "alarm";
+    38 => SYSCALL_SETITIMER, Setitimer, "setitimer";
+    39 => SYSCALL_GETPID, Getpid, "getpid";
+    40 => SYSCALL_SENDFILE, Sendfile, "sendfile";
+    41 => SYSCALL_SOCKET, Socket, "socket";
+    42 => SYSCALL_CONNECT, Connect, "connect";
+    43 => SYSCALL_ACCEPT, Accept, "accept";
+    44 => SYSCALL_SENDTO, Sendto, "sendto";
+    45 => SYSCALL_RECVFROM, Recvfrom, "recvfrom";
+    46 => SYSCALL_SENDMSG, Sendmsg, "sendmsg";
+    47 => SYSCALL_RECVMSG, Recvmsg, "recvmsg";
+    48 => SYSCALL_SHUTDOWN, Shutdown, "shutdown";
+    49 => SYSCALL_BIND, Bind, "bind";
+    50 => SYSCALL_LISTEN, Listen, "listen";
+    51 => SYSCALL_GETSOCKNAME, Getsockname, "getsockname";
+    52 => SYSCALL_GETPEERNAME, Getpeername, "getpeername";
+    53 => SYSCALL_SOCKETPAIR, Socketpair, "socketpair";
+    54 => SYSCALL_SETSOCKOPT, Setsockopt, "setsockopt";
+    55 => SYSCALL_GETSOCKOPT, Getsockopt, "getsockopt";
+    56 => SYSCALL_CLONE, Clone, "clone";
+    57 => SYSCALL_FORK, Fork, "fork";
+    58 => SYSCALL_VFORK, Vfork, "vfork";
+    59 => SYSCALL_EXECVE, Execve, "execve";
+    60 => SYSCALL_EXIT, Exit, "exit";
+    61 => SYSCALL_WAIT4, Wait4, "wait4";
+    62 => SYSCALL_KILL, Kill, "kill";
+    63 => SYSCALL_UNAME, Uname, "uname";
+    64 => SYSCALL_SEMGET, Semget, "semget";
+    65 => SYSCALL_SEMOP, Semop, "semop";
+    66 => SYSCALL_SEMCTL, Semctl, "semctl";
+    67 => SYSCALL_SHAMDT, Shmdt, "shmdt";
+    68 => SYSCALL_MSGGET, Msgget, "msgget";
+    69 => SYSCALL_MSGSND, Msgsnd, "msgsnd";
+    70 => SYSCALL_MSGRCV, Msgrcv, "msgrcv";
+    71 => SYSCALL_MSGCTL, Msgctl, "msgctl";
+    72 => SYSCALL_FCNTL, Fcntl, "fcntl";
+    73 => SYSCALL_FLOCK, Flock, "flock";
+    74 => SYSCALL_FSYNC, Fsync, "fsync";
+    75 => SYSCALL_FDATASYNC, Fdatasync, "fdatasync";
+    76 => SYSCALL_TRUNCATE, Truncate, "truncate";
+    77 => SYSCALL_FTRUNCATE, Ftruncate, "ftruncate";
+    78 => SYSCALL_GETDENTS, Getdents, "getdents";
+    79 => SYSCALL_GETCWD, Getcwd, "getcwd";
+    80 => SYSCALL_CHDIR, Chdir, "chdir";
+    81 => SYSCALL_FCHDIR, Fchdir, "fchdir";
+    82 => SYSCALL_RENAME, Rename, "rename";
+    83 => SYSCALL_MKDIR, Mkdir, "mkdir";
+    84 => SYSCALL_RMDIR, Rmdir, "rmdir";
+    85 => SYSCALL_CREAT, Creat, "creat";
+    86 => SYSCALL_LINK, Link, "link";
+    87 => SYSCALL_UNLINK, Unlink, "unlink";
+    88 => SYSCALL_SYMLINK, Symlink, "symlink";
+    89 => SYSCALL_READLINK, Readlink, "readlink";
+    90 => SYSCALL_CHMOD, Chmod, "chmod";
+    91 => SYSCALL_FCHMOD, Fchmod, "fchmod";
+    92 => SYSCALL_CHOWN, Chown, "chown";
+    93 => SYSCALL_FCHOWN, Fchown, "fchown";
+    94 => SYSCALL_LCHOWN, Lchown, "lchown";
+    95 => SYSCALL_UMASK, Umask, "umask";
+    96 => SYSCALL_GET_TIME_OF_DAY, Gettimeofday, "gettimeofday";
+    97 => SYSCALL_GETRLIMIT, Getrlimit, "getrlimit";
+    98 => SYSCALL_GETRUSAGE, Getrusage, "getrusage";
+    99 => SYSCALL_SYSINFO, Sysinfo, "sysinfo";
+    100 => SYSCALL_TIMES, Times, "times";
+    101 => SYSCALL_PTRACE, Ptrace, "ptrace";
+    102 => SYSCALL_GETUID, Getuid, "getuid";
+    103 => SYSCALL_SYSLOG, Syslog, "syslog";
+    104 => SYSCALL_GETGID, Getgid, "getgid";
+    105 => SYSCALL_SETUID, Setuid, "setuid";
+    106 => SYSCALL_SETGID, Setgid, "setgid";
+    107 => SYSCALL_GETEUID, Geteuid, "geteuid";
+    108 => SYSCALL_GETEGID, Getegid, "getegid";
+    109 => SYSCALL_SETPGID, Setpgid, "setpgid";
+    110 => SYSCALL_GETPPID, Getppid, "getppid";
+    111 => SYSCALL_GETPGRP, Getpgrp, "getpgrp";
+    112 => SYSCALL_SETSID, Setsid, "setsid";
+    113 => SYSCALL_SETREUID, Setreuid, "setreuid";
+    114 => SYSCALL_SETREGID, Setregid, "setregid";
+    115 => SYSCALL_GETGROUPS, Getgroups, "getgroups";
+    116 => SYSCALL_SETGROUPS, Setgroups, "setgroups";
+    117 => SYSCALL_SETRESUID, Setresuid, "setresuid";
+    118 => SYSCALL_GETRESUID, Getresuid, "getresuid";
+    119 => SYSCALL_SETRESGID, Setresgid, "setresgid";
+    120 => SYSCALL_GETRESGID, Getresgid, "getresgid";
+    121 => SYSCALL_GETPGID, Getpgid, "getpgid";
+    122 => SYSCALL_SETFSUID, Setfsuid, "setfsuid";
+    123 => SYSCALL_SETFSGID, Setfsgid, "setfsgid";
+    124 => SYSCALL_GETSID, Getsid, "getsid";
+    125 => SYSCALL_CAPGET, Capget, "capget";
+    126 => SYSCALL_CAPSET, Capset, "capset";
+    127 => SYSCALL_RT_SIGPENDING, RtSigpending, "rt_sigpending";
+    128 => SYSCALL_SIGTIMEDWAIT, RtSigtimedwait, "rt_sigtimedwait";
+    129 => SYSCALL_RT_SIGQUEUEINFO, RtSigqueueinfo, "rt_sigqueueinfo";
+    130 => SYSCALL_SIGSUSPEND, RtSigsuspend, "rt_sigsuspend";
+    131 => SYSCALL_SIGALTSTACK, Sigaltstack, "sigaltstack";
+    132 => SYSCALL_UTIME, Utime, "utime";
+    133 => SYSCALL_MKNOD, Mknod, "mknod";
+    134 => SYSCALL_USELIB, Uselib, "uselib";
+    135 => SYSCALL_PERSONALITY, Personality, "personality";
+    136 => SYSCALL_USTAT, Ustat, "ustat";
+    137 => SYSCALL_STATFS, Statfs, "statfs";
+    138 => SYSCALL_FSTATFS, Fstatfs, "fstatfs";
+    139 => SYSCALL_SYSFS, Sysfs, "sysfs";
+    140 => SYSCALL_GETPRIORITY, Getpriority, "getpriority";
+    141 => SYSCALL_SETPRIORITY, Setpriority, "setpriority";
+    142 => SYSCALL_SCHED_SETPARAM, SchedSetparam, "sched_setparam";
+    143 => SYSCALL_SCHED_GETPARAM, SchedGetparam, "sched_getparam";
+    144 => SYSCALL_SCHED_SETSCHEDULER, SchedSetscheduler, "sched_setscheduler";
+    145 => SYSCALL_SCHED_GETSCHEDULER, SchedGetscheduler, "sched_getscheduler";
+    146 => SYSCALL_SCHED_GET_PRIORITY_MAX, SchedGetPriorityMax, "sched_get_priority_max";
+    147 => SYSCALL_SCHED_GET_PRIORITY_MIN, SchedGetPriorityMin, "sched_get_priority_min";
+    148 => SYSCALL_SCHED_RR_GET_INTERVAL, SchedRrGetInterval, "sched_rr_get_interval";
+    149 => SYSCALL_MLOCK, Mlock, "mlock";
+    150 => SYSCALL_MUNLOCK, Munlock, "munlock";
+    151 => SYSCALL_MLOCKALL, Mlockall, "mlockall";
+    152 => SYSCALL_MUNLOCKALL, Munlockall, "munlockall";
+    153 => SYSCALL_VHANGUP, Vhangup, "vhangup";
+    154 => SYSCALL_MODIFY_LDT, ModifyLdt, "modify_ldt";
+    155 => SYSCALL_PIVOT_ROOT, PivotRoot, "pivot_root";
+    157 => SYSCALL_PRCTL, Prctl, "prctl";
+    158 => SYSCALL_ARCH_PRCTL, ArchPrctl, "arch_prctl";
+    159 => SYSCALL_ADJTIMEX, Adjtimex, "adjtimex";
+    160 => SYSCALL_SETRLIMIT, Setrlimit, "setrlimit";
+    161 => SYSCALL_CHROOT, Chroot, "chroot";
+    162 => SYSCALL_SYNC, Sync, "sync";
+    163 => SYSCALL_ACCT, Acct, "acct";
+    164 => SYSCALL_SETTIMEOFDAY, Settimeofday, "settimeofday";
+    165 => SYSCALL_MOUNT, Mount, "mount";
+    166 => SYSCALL_UMOUNT2, Umount2, "umount2";
+    167 => SYSCALL_SWAPON, Swapon, "swapon";
+    168 => SYSCALL_SWAPOFF, Swapoff, "swapoff";
+    169 => SYSCALL_REBOOT, Reboot, "reboot";
+    170 => SYSCALL_SETHOSTNAME, Sethostname, "sethostname";
+    171 => SYSCALL_SETDOMAINNAME, Setdomainname, "setdomainname";
+    172 => SYSCALL_IOPL, Iopl, "iopl";
+    173 => SYSCALL_IOPERM, Ioperm, "ioperm";
+    175 => SYSCALL_INIT_MODULE, InitModule, "init_module";
+    176 => SYSCALL_DELETE_MODULE, DeleteModule, "delete_module";
+    179 => SYSCALL_QUOTACTL, Quotactl, "quotactl";
+    186 => SYSCALL_GETTID, Gettid, "gettid";
+    187 => SYSCALL_READAHEAD, Readahead, "readahead";
+    188 => SYSCALL_SETXATTR, Setxattr, "setxattr";
+    189 => SYSCALL_LSETXATTR, Lsetxattr, "lsetxattr";
+    190 => SYSCALL_FSETXATTR, Fsetxattr, "fsetxattr";
+    191 => SYSCALL_GETXATTR, Getxattr, "getxattr";
+    192 => SYSCALL_LGETXATTR, Lgetxattr, "lgetxattr";
+    193 => SYSCALL_FGETXATTR, Fgetxattr, "fgetxattr";
+    194 => SYSCALL_LISTXATTR, Listxattr, "listxattr";
+    195 => SYSCALL_LLISTXATTR, Llistxattr, "llistxattr";
+    196 => SYSCALL_FLISTXATTR, Flistxattr, "flistxattr";
+    197 => SYSCALL_REMOVEXATTR, Removexattr, "removexattr";
+    198 => SYSCALL_LREMOVEXATTR, Lremovexattr, "lremovexattr";
+    199 => SYSCALL_FREMOVEXATTR, Fremovexattr, "fremovexattr";
+    200 => SYSCALL_TKILL, Tkill, "tkill";
+    201 => SYSCALL_TIME, Time, "time";
+    202 => SYSCALL_FUTEX, Futex, "futex";
+    203 => SYSCALL_SCHED_SETAFFINITY, SchedSetaffinity, "sched_setaffinity";
+    204 => SYSCALL_SCHED_GETAFFINITY, SchedGetaffinity, "sched_getaffinity";
+    205 => SYSCALL_SET_THREAD_AREA, SetThreadArea, "set_thread_area";
+    206 => SYSCALL_IO_SETUP, IoSetup, "io_setup";
+    207 => SYSCALL_IO_DESTROY, IoDestroy, "io_destroy";
+    208 => SYSCALL_IO_GETEVENTS, IoGetevents, "io_getevents";
+    209 => SYSCALL_IO_SUBMIT, IoSubmit, "io_submit";
+    210 => SYSCALL_IO_CANCEL, IoCancel, "io_cancel";
+    211 => SYSCALL_GET_THREAD_AREA, GetThreadArea, "get_thread_area";
+    212 => SYSCALL_LOOKUP_DCOOKIE, LookupDcookie, "lookup_dcookie";
+    213 => SYSCALL_EPOLL_CREATE, EpollCreate, "epoll_create";
+    216 => SYSCALL_REMAP_FILE_PAGES, RemapFilePages, "remap_file_pages";
+    217 => SYSCALL_GETDENTS64, Getdents64, "getdents64";
+    218 => SYSCALL_SET_TID_ADDRESS, SetTidAddress, "set_tid_address";
+    219 => SYSCALL_RESTART_SYSCALL, RestartSyscall, "restart_syscall";
+    220 => SYSCALL_SEMTIMEDOP, Semtimedop, "semtimedop";
+    221 => SYSCALL_FADVISE64, Fadvise64, "fadvise64";
+    222 => SYSCALL_TIMER_CREATE, TimerCreate, "timer_create";
+    223 => SYSCALL_TIMER_SETTIME, TimerSettime, "timer_settime";
+    224 => SYSCALL_TIMER_GETTIME, TimerGettime, "timer_gettime";
+    225 => SYSCALL_TIMER_GETOVERRUN, TimerGetoverrun, "timer_getoverrun";
+    226 => SYSCALL_TIMER_DELETE, TimerDelete, "timer_delete";
+    227 => SYSCALL_CLOCK_SETTIME, ClockSettime, "clock_settime";
+    228 => SYSCALL_CLOCK_GETTIME, ClockGettime, "clock_gettime";
+    229 => SYSCALL_CLOCK_GETRES, ClockGetres, "clock_getres";
+    230 => SYSCALL_CLOCK_NANOSLEEP, ClockNanosleep, "clock_nanosleep";
+    231 => SYSCALL_EXIT_GROUP, ExitGroup, "exit_group";
+    232 => SYSCALL_EPOLL_WAIT, EpollWait, "epoll_wait";
+    233 => SYSCALL_EPOLL_CTL, EpollCtl, "epoll_ctl";
+    234 => SYSCALL_TGKILL, Tgkill, "tgkill";
+    235 => SYSCALL_UTIMES, Utimes, "utimes";
+    237 => SYSCALL_MBIND, Mbind, "mbind";
+    238 => SYSCALL_SET_MEMPOLICY, SetMempolicy, "set_mempolicy";
+    239 => SYSCALL_GET_MEMPOLICY, GetMempolicy, "get_mempolicy";
+    240 => SYSCALL_MQ_OPEN, MqOpen, "mq_open";
+    241 => SYSCALL_MQ_UNLINK, MqUnlink, "mq_unlink";
+    242 => SYSCALL_MQ_TIMEDSEND, MqTimedsend, "mq_timedsend";
+    243 => SYSCALL_MQ_TIMEDRECEIVE, MqTimedreceive, "mq_timedreceive";
+    244 => SYSCALL_MQ_NOTIFY, MqNotify, "mq_notify";
+    245 => SYSCALL_MQ_GETSETATTR, MqGetsetattr, "mq_getsetattr";
+    246 => SYSCALL_KEXEC_LOAD, KexecLoad, "kexec_load";
+    247 => SYSCALL_WAITID, Waitid, "waitid";
+    248 => SYSCALL_ADD_KEY, AddKey, "add_key";
+    249 => SYSCALL_REQUEST_KEY, RequestKey, "request_key";
+    250 => SYSCALL_KEYCTL, Keyctl, "keyctl";
+    251 => SYSCALL_IOPRIO_SET, IoprioSet, "ioprio_set";
+    252 => SYSCALL_IOPRIO_GET, IoprioGet, "ioprio_get";
+    253 => SYSCALL_INOTIFY_INIT, InotifyInit, "inotify_init";
+    254 => SYSCALL_INOTIFY_ADD_WATCH, InotifyAddWatch, "inotify_add_watch";
+    255 => SYSCALL_INOTIFY_RM_WATCH, InotifyRmWatch, "inotify_rm_watch";
+    256 => SYSCALL_MIGRATE_PAGES, MigratePages, "migrate_pages";
+    257 => SYSCALL_OPENAT, Openat, "openat";
+    258 => SYSCALL_MKDIRAT, Mkdirat, "mkdirat";
+    259 => SYSCALL_MKNODAT, Mknodat, "mknodat";
+    260 => SYSCALL_FCHOWNAT, Fchownat, "fchownat";
+    261 => SYSCALL_FUTIMESAT, Futimesat, "futimesat";
+    262 => SYSCALL_FSTATAT, Newfstatat, "newfstatat";
+    263 => SYSCALL_UNLINKAT, Unlinkat, "unlinkat";
+    264 => SYSCALL_RENAMEAT, Renameat, "renameat";
+    265 => SYSCALL_LINKAT, Linkat, "linkat";
+    266 => SYSCALL_SYMLINKAT, Symlinkat, "symlinkat";
+    267 => SYSCALL_READLINKAT, Readlinkat, "readlinkat";
+    268 => SYSCALL_FCHMODAT, Fchmodat, "fchmodat";
+    269 => SYSCALL_FACCESSAT, Faccessat, "faccessat";
+    270 => SYSCALL_PSELECT6, Pselect6, "pselect6";
+    271 => SYSCALL_PPOLL, Ppoll, "ppoll";
+    272 => SYSCALL_UNSHARE, Unshare, "unshare";
+    273 => SYSCALL_SET_ROBUST_LIST, SetRobustList, "set_robust_list";
+    274 => SYSCALL_GET_ROBUST_LIST, GetRobustList, "get_robust_list";
+    275 => SYSCALL_SPLICE, Splice, "splice";
+    276 => SYSCALL_TEE, Tee, "tee";
+    277 => SYSCALL_SYNC_FILE_RANGE, SyncFileRange, "sync_file_range";
+    278 => SYSCALL_VMSPLICE, Vmsplice, "vmsplice";
+    279 => SYSCALL_MOVE_PAGES, MovePages, "move_pages";
+    280 => SYSCALL_UTIMENSAT, Utimensat, "utimensat";
+    281 => SYSCALL_EPOLL_PWAIT, EpollPwait, "epoll_pwait";
+    282 => SYSCALL_SIGNALFD, Signalfd, "signalfd";
+    283 => SYSCALL_TIMERFD_CREATE, TimerfdCreate, "timerfd_create";
+    284 => SYSCALL_EVENTFD, Eventfd, "eventfd";
+    285 => SYSCALL_FALLOCATE, Fallocate, "fallocate";
+    286 => SYSCALL_TIMERFD_SETTIME, TimerfdSettime, "timerfd_settime";
+    287 => SYSCALL_TIMERFD_GETTIME, TimerfdGettime, "timerfd_gettime";
+    288 => SYSCALL_ACCEPT4, Accept4, "accept4";
+    289 => SYSCALL_SIGNALFD4, Signalfd4, "signalfd4";
+    290 => SYSCALL_EVENTFD2, Eventfd2, "eventfd2";
+    291 => SYSCALL_EPOLL_CREATE1, EpollCreate1, "epoll_create1";
+    292 => SYSCALL_DUP3, Dup3, "dup3";
+    293 => SYSCALL_PIPE2, Pipe2, "pipe2";
+    294 => SYSCALL_INOTIFY_INIT1, InotifyInit1, "inotify_init1";
+    295 => SYSCALL_PREADV, Preadv, "preadv";
+    296 => SYSCALL_PWRITEV, Pwritev, "pwritev";
+    297 => SYSCALL_RT_TGSIGQUEUEINFO, RtTgsigqueueinfo, "rt_tgsigqueueinfo";
+    298 => SYSCALL_PERF_EVENT_OPEN, PerfEventOpen, "perf_event_open";
+    299 => SYSCALL_RECVMMSG, Recvmmsg, "recvmmsg";
+    300 => SYSCALL_FANOTIFY_INIT, FanotifyInit, "fanotify_init";
+    301 => SYSCALL_FANOTIFY_MARK, FanotifyMark, "fanotify_mark";
+    302 => SYSCALL_PRLIMIT, Prlimit64, "prlimit64";
+    303 => SYSCALL_NAME_TO_HANDLE_AT, NameToHandleAt, "name_to_handle_at";
+    304 => SYSCALL_OPEN_BY_HANDLE_AT, OpenByHandleAt, "open_by_handle_at";
+    305 => SYSCALL_CLOCK_ADJTIME, ClockAdjtime, "clock_adjtime";
+    306 => SYSCALL_SYNCFS, Syncfs, "syncfs";
+    307 => SYSCALL_SENDMMSG, Sendmmsg, "sendmmsg";
+    308 => SYSCALL_SETNS, Setns, "setns";
+    309 => SYSCALL_GETCPU, Getcpu, "getcpu";
+    310 => SYSCALL_PROCESS_VM_READV, ProcessVmReadv, "process_vm_readv";
+    311 => SYSCALL_PROCESS_VM_WRITEV, ProcessVmWritev, "process_vm_writev";
+    312 => SYSCALL_KCMP, Kcmp, "kcmp";
+    313 => SYSCALL_FINIT_MODULE, FinitModule, "finit_module";
+    314 => SYSCALL_SCHED_SETATTR, SchedSetattr, "sched_setattr";
+    315 => SYSCALL_SCHED_GETATTR, SchedGetattr, "sched_getattr";
+    316 => SYSCALL_RENAMEAT2, Renameat2, "renameat2";
+    317 => SYSCALL_SECCOMP, Seccomp, "seccomp";
+    318 => SYSCALL_GETRANDOM, Getrandom, "getrandom";
+    319 => SYSCALL_MEMFD_CREATE, MemfdCreate, "memfd_create";
+    320 => SYSCALL_KEXEC_FILE_LOAD, KexecFileLoad, "kexec_file_load";
+    321 => SYSCALL_BPF, Bpf, "bpf";
+    322 => SYSCALL_EXECVEAT, Execveat, "execveat";
+    323 => SYSCALL_USERFAULTFD, Userfaultfd, "userfaultfd";
+    324 => SYSCALL_MEMBARRIER, Membarrier, "membarrier";
+    325 => SYSCALL_MLOCK2, Mlock2, "mlock2";
+    326 => SYSCALL_COPY_FILE_RANGE, CopyFileRange, "copy_file_range";
+    327 => SYSCALL_PREADV2, Preadv2, "preadv2";
+    328 => SYSCALL_PWRITEV2, Pwritev2, "pwritev2";
+    329 => SYSCALL_PKEY_MPROTECT, PkeyMprotect, "pkey_mprotect";
+    330 => SYSCALL_PKEY_ALLOC, PkeyAlloc, "pkey_alloc";
+    331 => SYSCALL_PKEY_FREE, PkeyFree, "pkey_free";
+    332 => SYSCALL_STATX, Statx, "statx";
+    333 => SYSCALL_IO_PGETEVENTS, IoPgetevents, "io_pgetevents";
+    334 => SYSCALL_RSEQ, Rseq, "rseq";
+    424 => SYSCALL_PIDFD_SEND_SIGNAL, PidfdSendSignal, "pidfd_send_signal";
+    425 => SYSCALL_IO_URING_SETUP, IoUringSetup, "io_uring_setup";
+    426 => SYSCALL_IO_URING_ENTER, IoUringEnter, "io_uring_enter";
+    427 => SYSCALL_IO_URING_REGISTER, IoUringRegister, "io_uring_register";
+    428 => SYSCALL_OPEN_TREE, OpenTree, "open_tree";
+    429 => SYSCALL_MOVE_MOUNT, MoveMount, "move_mount";
+    430 => SYSCALL_FSOPEN, Fsopen, "fsopen";
+    431 => SYSCALL_FSCONFIG, Fsconfig, "fsconfig";
+    432 => SYSCALL_FSMOUNT, Fsmount, "fsmount";
+    433 => SYSCALL_FSPICK, Fspick, "fspick";
+    434 => SYSCALL_PIDFD_OPEN, PidfdOpen, "pidfd_open";
+    435 => SYSCALL_CLONE3, Clone3, "clone3";
+    436 => SYSCALL_CLOSE_RANGE, CloseRange, "close_range";
+    437 => SYSCALL_OPENAT2, Openat2, "openat2";
+    438 => SYSCALL_PIDFD_GETFD, PidfdGetfd, "pidfd_getfd";
+    439 => SYSCALL_FACCESSAT2, Faccessat2, "faccessat2";
+    440 => SYSCALL_PROCESS_MADVISE, ProcessMadvise, "process_madvise";
+    441 => SYSCALL_EPOLL_PWAIT2, EpollPwait2, "epoll_pwait2";
+    442 => SYSCALL_MOUNT_SETATTR, MountSetattr, "mount_setattr";
+    443 => SYSCALL_QUOTACTL_FD, QuotactlFd, "quotactl_fd";
+    444 => SYSCALL_LANDLOCK_CREATE_RULESET, LandlockCreateRuleset, "landlock_create_ruleset";
+    445 => SYSCALL_LANDLOCK_ADD_RULE, LandlockAddRule, "landlock_add_rule";
+    446 => SYSCALL_LANDLOCK_RESTRICT_SELF, LandlockRestrictSelf, "landlock_restrict_self";
+    447 => SYSCALL_MEMFD_SECRET, MemfdSecret, "memfd_secret";
+    448 => SYSCALL_PROCESS_MRELEASE, ProcessMrelease, "process_mrelease";
+    449 => SYSCALL_FUTEX_WAITV, FutexWaitv, "futex_waitv";
+    450 => SYSCALL_SET_MEMPOLICY_HOME_NODE, SetMempolicyHomeNode, "set_mempolicy_home_node";
+    451 => SYSCALL_CACHESTAT, Cachestat, "cachestat";
+    452 => SYSCALL_FCHMODAT2, Fchmodat2, "fchmodat2";
+    453 => SYSCALL_MAP_SHADOW_STACK, MapShadowStack, "map_shadow_stack";
+    454 => SYSCALL_FUTEX_WAKE, FutexWake, "futex_wake";
+    455 => SYSCALL_FUTEX_WAIT, FutexWait, "futex_wait";
+    456 => SYSCALL_FUTEX_REQUEUE, FutexRequeue, "futex_requeue";
+    457 => SYSCALL_STATMOUNT, Statmount, "statmount";
+    458 => SYSCALL_LISTMOUNT, Listmount, "listmount";
+    459 => SYSCALL_LSM_GET_SELF_ATTR, LsmGetSelfAttr, "lsm_get_self_attr";
+    460 => SYSCALL_LSM_SET_SELF_ATTR, LsmSetSelfAttr, "lsm_set_self_attr";
+    461 => SYSCALL_LSM_LIST_MODULES, LsmListModules, "lsm_list_modules";
+    462 => SYSCALL_MSEAL, Mseal, "mseal";
+}
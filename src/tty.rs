@@ -0,0 +1,1051 @@
+//! 终端的行规程 (line discipline)。
+//!
+//! [`LineDiscipline`] 按照 [`Termios`] 处理终端的输入：规范模式下的行编辑、回显、
+//! 产生信号，以及非规范模式下 `VMIN`/`VTIME` 的读取语义。它不依赖任何硬件，
+//! 输入的字节由驱动传入，回显与输出的字节由驱动取走后写到设备上。
+//!
+//! [`PtyPair`] 是一对伪终端：写入主设备的数据作为从设备的输入经过行规程，
+//! 从设备的输出与回显则由主设备读取。
+
+use alloc::{collections::VecDeque, vec::Vec};
+
+use crate::{
+    io::{
+        ControlChar, FlowAction, FlushQueue, InputModes, LocalModes, OutputModes, Termios, WinSize,
+    },
+    signal::SignalNumber,
+    LinuxErrno,
+};
+
+/// 规范模式下一行的最大长度，与 Linux 的 `N_TTY_BUF_SIZE - 1` 相同
+pub const MAX_CANON: usize = 4095;
+
+/// [`LineDiscipline::read`] 的结果
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ReadStatus {
+    /// 读到了这么多字节，0 表示文件结束或超时
+    Ready(usize),
+    /// 需要等待新的输入。`timeout` 为 `Some(t)` 时，最多等待 `t` 个 0.1 秒
+    /// （从现在或下一个字节到达时算起），超时后以 `timed_out` 为 `true` 重新读取
+    Block { timeout: Option<u8> },
+}
+
+/// 终端的行规程
+#[derive(Debug, Clone)]
+pub struct LineDiscipline {
+    termios: Termios,
+    /// 规范模式下正在编辑的行
+    line: Vec<u8>,
+    /// 可以被读取的数据
+    ready: VecDeque<u8>,
+    /// 规范模式下 `ready` 中每一行的长度，长度为 0 表示 VEOF
+    lines: VecDeque<usize>,
+    /// 等待写到设备上的回显与输出
+    output: Vec<u8>,
+    /// 上一个字符是 VLNEXT
+    literal_next: bool,
+}
+
+impl LineDiscipline {
+    pub fn new(termios: Termios) -> Self {
+        Self {
+            termios,
+            line: Vec::new(),
+            ready: VecDeque::new(),
+            lines: VecDeque::new(),
+            output: Vec::new(),
+            literal_next: false,
+        }
+    }
+
+    pub fn termios(&self) -> &Termios {
+        &self.termios
+    }
+
+    /// 修改终端设置。离开规范模式时，正在编辑的行立即变为可读；
+    /// 进入规范模式时，已有的输入被视为一行
+    pub fn set_termios(&mut self, termios: Termios) {
+        let was_canonical = self.is_canonical();
+        self.termios = termios;
+        match (was_canonical, self.is_canonical()) {
+            (true, false) => {
+                self.ready.extend(self.line.drain(..));
+                self.lines.clear();
+            }
+            (false, true) if !self.ready.is_empty() => self.lines.push_back(self.ready.len()),
+            _ => {}
+        }
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.termios.local_modes().contains(LocalModes::ICANON)
+    }
+
+    /// 判断 `c` 是否为某个控制字符，值为 0 的控制字符被禁用
+    fn is_char(&self, c: u8, index: ControlChar) -> bool {
+        let value = self.termios.cc(index);
+        value != 0 && value == c
+    }
+
+    /// 可以被读取的字节数
+    pub fn available(&self) -> usize {
+        self.ready.len()
+    }
+
+    /// 丢弃所有尚未被读取的输入
+    pub fn flush_input(&mut self) {
+        self.line.clear();
+        self.ready.clear();
+        self.lines.clear();
+        self.literal_next = false;
+    }
+
+    /// 取走等待写到设备上的字节
+    pub fn take_output(&mut self) -> Vec<u8> {
+        core::mem::take(&mut self.output)
+    }
+
+    /// 按照输出模式处理进程写入终端的数据，结果放入输出队列
+    pub fn write(&mut self, data: &[u8]) {
+        for &c in data {
+            self.output_byte(c);
+        }
+    }
+
+    fn output_byte(&mut self, c: u8) {
+        let oflag = self.termios.output_modes();
+        if !oflag.contains(OutputModes::OPOST) {
+            self.output.push(c);
+            return;
+        }
+        match c {
+            b'\n' if oflag.contains(OutputModes::ONLCR) => self.output.extend_from_slice(b"\r\n"),
+            b'\r' if oflag.contains(OutputModes::OCRNL) => self.output.push(b'\n'),
+            _ => self.output.push(c),
+        }
+    }
+
+    /// 回显一个字符，`ECHOCTL` 时控制字符显示为 `^X`
+    fn echo_byte(&mut self, c: u8) {
+        let lflag = self.termios.local_modes();
+        if lflag.contains(LocalModes::ECHOCTL) && is_ctrl(c) {
+            self.output.push(b'^');
+            self.output.push(c ^ 0x40);
+        } else {
+            self.output_byte(c);
+        }
+    }
+
+    /// 在屏幕上擦除一个已回显的字符
+    fn echo_erase(&mut self, c: u8) {
+        let width = if self.termios.local_modes().contains(LocalModes::ECHOCTL) && is_ctrl(c) {
+            2
+        } else {
+            1
+        };
+        for _ in 0..width {
+            self.output.extend_from_slice(b"\x08 \x08");
+        }
+    }
+
+    /// 处理从设备收到的一个字节，需要发送信号时返回对应的信号
+    pub fn receive(&mut self, c: u8) -> Option<SignalNumber> {
+        let iflag = self.termios.input_modes();
+        let lflag = self.termios.local_modes();
+        let mut c = c;
+        if iflag.contains(InputModes::ISTRIP) {
+            c &= 0x7f;
+        }
+        if core::mem::take(&mut self.literal_next) {
+            self.push_char(c);
+            return None;
+        }
+        match c {
+            b'\r' if iflag.contains(InputModes::IGNCR) => return None,
+            b'\r' if iflag.contains(InputModes::ICRNL) => c = b'\n',
+            b'\n' if iflag.contains(InputModes::INLCR) => c = b'\r',
+            _ => {}
+        }
+        if lflag.contains(LocalModes::ISIG) {
+            let signal = if self.is_char(c, ControlChar::VINTR) {
+                Some(SignalNumber::SIGINT)
+            } else if self.is_char(c, ControlChar::VQUIT) {
+                Some(SignalNumber::SIGQUIT)
+            } else if self.is_char(c, ControlChar::VSUSP) {
+                Some(SignalNumber::SIGTSTP)
+            } else {
+                None
+            };
+            if signal.is_some() {
+                if !lflag.contains(LocalModes::NOFLSH) {
+                    self.flush_input();
+                }
+                if lflag.contains(LocalModes::ECHO) {
+                    self.echo_byte(c);
+                }
+                return signal;
+            }
+        }
+        if !self.is_canonical() {
+            self.push_char(c);
+            return None;
+        }
+        let extended = lflag.contains(LocalModes::IEXTEN);
+        if extended && self.is_char(c, ControlChar::VLNEXT) {
+            self.literal_next = true;
+        } else if self.is_char(c, ControlChar::VERASE) {
+            self.erase_char();
+        } else if extended && self.is_char(c, ControlChar::VWERASE) {
+            self.erase_word();
+        } else if self.is_char(c, ControlChar::VKILL) {
+            self.kill_line(c);
+        } else if self.is_char(c, ControlChar::VEOF) {
+            self.end_line();
+        } else if c == b'\n'
+            || self.is_char(c, ControlChar::VEOL)
+            || (extended && self.is_char(c, ControlChar::VEOL2))
+        {
+            self.line.push(c);
+            if lflag.intersects(LocalModes::ECHO | LocalModes::ECHONL) {
+                self.echo_byte(c);
+            }
+            self.end_line();
+        } else {
+            self.push_char(c);
+        }
+        None
+    }
+
+    /// 将普通字符放入输入，规范模式下一行满了之后丢弃
+    fn push_char(&mut self, c: u8) {
+        if !self.is_canonical() {
+            self.ready.push_back(c);
+        } else if self.line.len() < MAX_CANON {
+            self.line.push(c);
+        } else {
+            return;
+        }
+        if self.termios.local_modes().contains(LocalModes::ECHO) {
+            self.echo_byte(c);
+        }
+    }
+
+    /// 规范模式下结束当前行，使其可读
+    fn end_line(&mut self) {
+        self.lines.push_back(self.line.len());
+        self.ready.extend(self.line.drain(..));
+    }
+
+    /// 删除最后一个字符，`IUTF8` 时删除整个 UTF-8 字符
+    fn erase_char(&mut self) {
+        let utf8 = self.termios.input_modes().contains(InputModes::IUTF8);
+        while let Some(c) = self.line.pop() {
+            if !(utf8 && c & 0xc0 == 0x80) {
+                self.echo_erased(c);
+                return;
+            }
+        }
+    }
+
+    fn echo_erased(&mut self, c: u8) {
+        let lflag = self.termios.local_modes();
+        if lflag.contains(LocalModes::ECHO) && lflag.contains(LocalModes::ECHOE) {
+            self.echo_erase(c);
+        }
+    }
+
+    /// 删除前一个单词及其后的空白
+    fn erase_word(&mut self) {
+        while let Some(&c) = self.line.last() {
+            if !c.is_ascii_whitespace() {
+                break;
+            }
+            self.line.pop();
+            self.echo_erased(c);
+        }
+        while let Some(&c) = self.line.last() {
+            if c.is_ascii_whitespace() {
+                break;
+            }
+            self.line.pop();
+            self.echo_erased(c);
+        }
+    }
+
+    /// 删除整行
+    fn kill_line(&mut self, c: u8) {
+        let lflag = self.termios.local_modes();
+        if lflag.contains(LocalModes::ECHO) && lflag.contains(LocalModes::ECHOKE) {
+            while let Some(c) = self.line.pop() {
+                self.echo_erase(c);
+            }
+            return;
+        }
+        self.line.clear();
+        if lflag.contains(LocalModes::ECHO) && lflag.contains(LocalModes::ECHOK) {
+            self.echo_byte(c);
+            self.output_byte(b'\n');
+        }
+    }
+
+    /// 读取输入。
+    ///
+    /// 规范模式下一次最多读取一行，遇到 VEOF 时返回 `Ready(0)`。非规范模式下按照
+    /// `VMIN` 与 `VTIME` 决定是否等待；`timed_out` 表示上次返回的超时已经到期。
+    pub fn read(&mut self, buf: &mut [u8], timed_out: bool) -> ReadStatus {
+        if self.is_canonical() {
+            let Some(len) = self.lines.front_mut() else {
+                return ReadStatus::Block { timeout: None };
+            };
+            let n = (*len).min(buf.len());
+            *len -= n;
+            // 读完一行（或读到 VEOF）后才去掉这一行
+            if *len == 0 {
+                self.lines.pop_front();
+            }
+            for (dst, src) in buf.iter_mut().zip(self.ready.drain(..n)) {
+                *dst = src;
+            }
+            return ReadStatus::Ready(n);
+        }
+        let min = self.termios.cc(ControlChar::VMIN) as usize;
+        let time = self.termios.cc(ControlChar::VTIME);
+        let available = self.ready.len();
+        let enough = match (min, time) {
+            (0, 0) => true,
+            (0, _) => available > 0 || timed_out,
+            (_, 0) => available >= min.min(buf.len()),
+            (_, _) => available >= min.min(buf.len()) || (available > 0 && timed_out),
+        };
+        if !enough {
+            let timeout = match (min, time) {
+                (_, 0) => None,
+                (0, time) => Some(time),
+                // 字节间的超时在收到第一个字节后才开始计时
+                (_, time) if available > 0 => Some(time),
+                _ => None,
+            };
+            return ReadStatus::Block { timeout };
+        }
+        let n = available.min(buf.len());
+        for (dst, src) in buf.iter_mut().zip(self.ready.drain(..n)) {
+            *dst = src;
+        }
+        ReadStatus::Ready(n)
+    }
+}
+
+impl Default for LineDiscipline {
+    fn default() -> Self {
+        Self::new(Termios::default())
+    }
+}
+
+/// 是否为 `ECHOCTL` 时显示为 `^X` 的控制字符
+fn is_ctrl(c: u8) -> bool {
+    (c < 0x20 && c != b'\t' && c != b'\n') || c == 0x7f
+}
+
+/// 伪终端的一端
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PtySide {
+    /// `/dev/ptmx` 打开得到的主设备
+    Master,
+    /// `/dev/pts/N`
+    Slave,
+}
+
+/// 一对伪终端
+#[derive(Debug, Clone)]
+pub struct PtyPair {
+    /// `/dev/pts/N` 中的 `N`
+    index: u32,
+    /// 为 `true` 时不能打开从设备，由 `TIOCSPTLCK` 设置
+    locked: bool,
+    ldisc: LineDiscipline,
+    /// 等待主设备读取的数据
+    to_master: VecDeque<u8>,
+    /// 输出被 `TCOOFF` 或 VSTOP 暂停
+    stopped: bool,
+    /// 以该终端为控制终端的会话
+    session: Option<u32>,
+    /// 前台进程组
+    foreground: Option<u32>,
+    winsize: WinSize,
+    master_closed: bool,
+    /// 从设备的所有 fd 都已关闭
+    slave_closed: bool,
+}
+
+impl PtyPair {
+    /// 打开 `/dev/ptmx` 时创建，从设备初始为锁定状态，需要 `unlockpt` 解锁
+    pub fn new(index: u32) -> Self {
+        Self {
+            index,
+            locked: true,
+            ldisc: LineDiscipline::default(),
+            to_master: VecDeque::new(),
+            stopped: false,
+            session: None,
+            foreground: None,
+            winsize: WinSize::default(),
+            master_closed: false,
+            slave_closed: true,
+        }
+    }
+
+    /// `TIOCGPTN`
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// `TIOCSPTLCK`，参数非 0 时锁定
+    pub fn set_locked(&mut self, locked: bool) {
+        self.locked = locked;
+    }
+
+    /// 打开从设备，包括 `TIOCGPTPEER`。锁定或主设备已关闭时返回 `EIO`
+    pub fn open_slave(&mut self) -> Result<(), LinuxErrno> {
+        if self.locked || self.master_closed {
+            return Err(LinuxErrno::EIO);
+        }
+        self.slave_closed = false;
+        Ok(())
+    }
+
+    /// 某一端的最后一个 fd 被关闭。
+    ///
+    /// 关闭主设备时返回需要发送给前台进程组的 `SIGHUP`。
+    pub fn close(&mut self, side: PtySide) -> Option<SignalNumber> {
+        match side {
+            PtySide::Master => {
+                self.master_closed = true;
+                self.session
+                    .and(self.foreground)
+                    .map(|_| SignalNumber::SIGHUP)
+            }
+            PtySide::Slave => {
+                self.slave_closed = true;
+                None
+            }
+        }
+    }
+
+    pub fn termios(&self) -> &Termios {
+        self.ldisc.termios()
+    }
+
+    pub fn set_termios(&mut self, termios: Termios) {
+        self.ldisc.set_termios(termios);
+        self.collect_output();
+    }
+
+    /// `TIOCGWINSZ`
+    pub fn winsize(&self) -> WinSize {
+        self.winsize
+    }
+
+    /// `TIOCSWINSZ`，两端都可以设置。
+    ///
+    /// 大小改变且存在前台进程组时返回需要发送给它的 `SIGWINCH`。
+    pub fn set_winsize(&mut self, winsize: WinSize) -> Option<SignalNumber> {
+        let signal = self.winsize.update(winsize);
+        self.foreground.and(signal)
+    }
+
+    /// 将行规程产生的输出移到主设备的读队列
+    fn collect_output(&mut self) {
+        self.to_master.extend(self.ldisc.take_output());
+    }
+
+    /// 向主设备写入，即终端收到的输入。返回需要发送给前台进程组的信号
+    pub fn master_write(&mut self, data: &[u8]) -> Vec<SignalNumber> {
+        let mut signals = Vec::new();
+        for &c in data {
+            signals.extend(self.receive(c));
+        }
+        signals
+    }
+
+    /// 处理一个输入字节，`IXON` 时 VSTOP 与 VSTART 控制输出
+    fn receive(&mut self, c: u8) -> Option<SignalNumber> {
+        let termios = self.ldisc.termios();
+        if termios.input_modes().contains(InputModes::IXON) {
+            if c != 0 && c == termios.cc(ControlChar::VSTOP) {
+                self.stopped = true;
+                return None;
+            }
+            if c != 0 && c == termios.cc(ControlChar::VSTART) {
+                self.stopped = false;
+                return None;
+            }
+        }
+        let signal = self.ldisc.receive(c);
+        self.collect_output();
+        signal
+    }
+
+    /// 读取主设备。从设备已关闭且没有数据时返回 `EIO`
+    pub fn master_read(&mut self, buf: &mut [u8]) -> Result<ReadStatus, LinuxErrno> {
+        if self.to_master.is_empty() || self.stopped {
+            if self.slave_closed && self.to_master.is_empty() {
+                return Err(LinuxErrno::EIO);
+            }
+            return Ok(ReadStatus::Block { timeout: None });
+        }
+        let n = self.to_master.len().min(buf.len());
+        for (dst, src) in buf.iter_mut().zip(self.to_master.drain(..n)) {
+            *dst = src;
+        }
+        Ok(ReadStatus::Ready(n))
+    }
+
+    /// 向从设备写入，数据经过输出处理后由主设备读取。主设备已关闭时返回 `EIO`
+    pub fn slave_write(&mut self, data: &[u8]) -> Result<usize, LinuxErrno> {
+        if self.master_closed {
+            return Err(LinuxErrno::EIO);
+        }
+        self.ldisc.write(data);
+        self.collect_output();
+        Ok(data.len())
+    }
+
+    /// 读取从设备，主设备已关闭时不再等待，返回文件结束
+    pub fn slave_read(&mut self, buf: &mut [u8], timed_out: bool) -> ReadStatus {
+        match self.ldisc.read(buf, timed_out) {
+            ReadStatus::Block { .. } if self.master_closed => ReadStatus::Ready(0),
+            status => status,
+        }
+    }
+
+    /// `TIOCSTI`：把一个字节当作终端的输入
+    pub fn simulate_input(&mut self, c: u8) -> Option<SignalNumber> {
+        self.receive(c)
+    }
+
+    /// `FIONREAD`：可以从这一端读取的字节数
+    pub fn readable(&self, side: PtySide) -> usize {
+        match side {
+            PtySide::Master => self.to_master.len(),
+            PtySide::Slave => self.ldisc.available(),
+        }
+    }
+
+    /// `TIOCOUTQ`：这一端写入后尚未被对端读取的字节数
+    pub fn queued_output(&self, side: PtySide) -> usize {
+        match side {
+            PtySide::Master => self.ldisc.available(),
+            PtySide::Slave => self.to_master.len(),
+        }
+    }
+
+    /// `TCFLSH`，输入与输出都是相对于 `side` 而言的
+    pub fn flush(&mut self, side: PtySide, queue: FlushQueue) {
+        let (input, output) = match queue {
+            FlushQueue::TCIFLUSH => (true, false),
+            FlushQueue::TCOFLUSH => (false, true),
+            FlushQueue::TCIOFLUSH => (true, true),
+        };
+        let (to_master, to_slave) = match side {
+            PtySide::Master => (input, output),
+            PtySide::Slave => (output, input),
+        };
+        if to_master {
+            self.to_master.clear();
+        }
+        if to_slave {
+            self.ldisc.flush_input();
+        }
+    }
+
+    /// `TCXONC`，由从设备调用
+    pub fn flow(&mut self, action: FlowAction) {
+        match action {
+            FlowAction::TCOOFF => self.stopped = true,
+            FlowAction::TCOON => self.stopped = false,
+            FlowAction::TCIOFF => self.send_control(ControlChar::VSTOP),
+            FlowAction::TCION => self.send_control(ControlChar::VSTART),
+        }
+    }
+
+    fn send_control(&mut self, index: ControlChar) {
+        let c = self.ldisc.termios().cc(index);
+        if c != 0 {
+            self.to_master.push_back(c);
+        }
+    }
+
+    /// `TIOCSCTTY`：使该终端成为会话 `sid` 的控制终端，`pgrp` 成为前台进程组。
+    ///
+    /// 终端已属于其他会话时，只有 `steal` 为 `true`（参数为 1 且有
+    /// `CAP_SYS_ADMIN`）才能夺取，否则返回 `EPERM`。
+    pub fn set_controlling(&mut self, sid: u32, pgrp: u32, steal: bool) -> Result<(), LinuxErrno> {
+        match self.session {
+            Some(session) if session == sid => return Ok(()),
+            Some(_) if !steal => return Err(LinuxErrno::EPERM),
+            _ => {}
+        }
+        self.session = Some(sid);
+        self.foreground = Some(pgrp);
+        Ok(())
+    }
+
+    /// `TIOCNOTTY`：会话 `sid` 放弃该控制终端。
+    ///
+    /// 由会话首进程调用时返回前台进程组，需要向它发送 `SIGHUP` 与 `SIGCONT`。
+    pub fn release_controlling(
+        &mut self,
+        sid: u32,
+        session_leader: bool,
+    ) -> Result<Option<u32>, LinuxErrno> {
+        if self.session != Some(sid) {
+            return Err(LinuxErrno::ENOTTY);
+        }
+        if !session_leader {
+            return Ok(None);
+        }
+        self.session = None;
+        Ok(self.foreground.take())
+    }
+
+    /// `TIOCGSID`，不是控制终端时返回 `ENOTTY`
+    pub fn session(&self) -> Result<u32, LinuxErrno> {
+        self.session.ok_or(LinuxErrno::ENOTTY)
+    }
+
+    /// `TIOCGPGRP`，`sid` 为调用者的会话
+    pub fn foreground(&self, sid: u32) -> Result<u32, LinuxErrno> {
+        if self.session != Some(sid) {
+            return Err(LinuxErrno::ENOTTY);
+        }
+        self.foreground.ok_or(LinuxErrno::ENOTTY)
+    }
+
+    /// `TIOCSPGRP`，调用者需要检查 `pgrp` 属于会话 `sid`
+    pub fn set_foreground(&mut self, sid: u32, pgrp: u32) -> Result<(), LinuxErrno> {
+        if self.session != Some(sid) {
+            return Err(LinuxErrno::ENOTTY);
+        }
+        self.foreground = Some(pgrp);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed(ld: &mut LineDiscipline, input: &[u8]) {
+        for &c in input {
+            assert_eq!(ld.receive(c), None);
+        }
+    }
+
+    fn read_all(ld: &mut LineDiscipline) -> Vec<u8> {
+        let mut buf = [0u8; 64];
+        match ld.read(&mut buf, false) {
+            ReadStatus::Ready(n) => buf[..n].to_vec(),
+            status => panic!("unexpected {:?}", status),
+        }
+    }
+
+    fn with_termios(f: impl FnOnce(&mut Termios)) -> LineDiscipline {
+        let mut termios = Termios::default();
+        f(&mut termios);
+        LineDiscipline::new(termios)
+    }
+
+    fn raw(min: u8, time: u8) -> LineDiscipline {
+        with_termios(|t| {
+            t.cfmakeraw();
+            t.set_cc(ControlChar::VMIN, min);
+            t.set_cc(ControlChar::VTIME, time);
+        })
+    }
+
+    #[test]
+    fn erase_word_and_kill() {
+        let mut ld = LineDiscipline::default();
+        feed(&mut ld, b"helo\x7flo");
+        feed(&mut ld, b" wrld\x17world\n");
+        assert_eq!(read_all(&mut ld), b"hello world\n");
+        // VWERASE 先删除空白，再删除单词
+        feed(&mut ld, b"foo bar  \x17\n");
+        assert_eq!(read_all(&mut ld), b"foo \n");
+        ld.take_output();
+        feed(&mut ld, b"abc\x15d\n");
+        assert_eq!(read_all(&mut ld), b"d\n");
+        // ECHOKE 逐个擦除已回显的字符
+        assert_eq!(ld.take_output(), b"abc\x08 \x08\x08 \x08\x08 \x08d\r\n");
+        // 空行上的 VERASE 不做任何事
+        feed(&mut ld, b"\x7f\n");
+        assert_eq!(read_all(&mut ld), b"\n");
+    }
+
+    #[test]
+    fn erase_utf8() {
+        let mut ld = LineDiscipline::default();
+        assert!(ld.termios().input_modes().contains(InputModes::IUTF8));
+        feed(&mut ld, "aé".as_bytes());
+        feed(&mut ld, b"\x7f\n");
+        assert_eq!(read_all(&mut ld), b"a\n");
+
+        let mut ld = with_termios(|t| {
+            t.set_input_modes(t.input_modes() - InputModes::IUTF8);
+        });
+        feed(&mut ld, "aé".as_bytes());
+        feed(&mut ld, b"\x7f\n");
+        assert_eq!(read_all(&mut ld), b"a\xc3\n");
+    }
+
+    #[test]
+    fn eof() {
+        let mut ld = LineDiscipline::default();
+        let mut buf = [0u8; 8];
+        // 空行上的 VEOF 使 read 返回 0
+        feed(&mut ld, b"\x04");
+        assert_eq!(ld.read(&mut buf, false), ReadStatus::Ready(0));
+        assert_eq!(
+            ld.read(&mut buf, false),
+            ReadStatus::Block { timeout: None }
+        );
+        // 非空行上的 VEOF 结束这一行，不附加换行
+        feed(&mut ld, b"ab\x04cd\n");
+        assert_eq!(read_all(&mut ld), b"ab");
+        assert_eq!(read_all(&mut ld), b"cd\n");
+        // 缓冲区不足时分多次读完一行
+        feed(&mut ld, b"abcdef\n");
+        assert_eq!(ld.read(&mut buf[..4], false), ReadStatus::Ready(4));
+        assert_eq!(read_all(&mut ld), b"ef\n");
+    }
+
+    #[test]
+    fn newline_translation() {
+        let mut ld = LineDiscipline::default();
+        feed(&mut ld, b"ls\r");
+        assert_eq!(read_all(&mut ld), b"ls\n");
+        assert_eq!(ld.take_output(), b"ls\r\n");
+        ld.write(b"a\nb");
+        assert_eq!(ld.take_output(), b"a\r\nb");
+
+        let mut ld = with_termios(|t| {
+            t.set_input_modes(t.input_modes() - InputModes::ICRNL);
+            t.set_output_modes(t.output_modes() - OutputModes::ONLCR);
+        });
+        feed(&mut ld, b"a\rb\n");
+        assert_eq!(read_all(&mut ld), b"a\rb\n");
+        ld.take_output();
+        ld.write(b"a\n");
+        assert_eq!(ld.take_output(), b"a\n");
+
+        let mut ld = with_termios(|t| t.set_input_modes(t.input_modes() | InputModes::IGNCR));
+        feed(&mut ld, b"a\r\n");
+        assert_eq!(read_all(&mut ld), b"a\n");
+    }
+
+    #[test]
+    fn signals() {
+        let mut ld = LineDiscipline::default();
+        feed(&mut ld, b"ab");
+        assert_eq!(ld.receive(0x03), Some(SignalNumber::SIGINT));
+        assert_eq!(ld.receive(0x1c), Some(SignalNumber::SIGQUIT));
+        assert_eq!(ld.receive(0x1a), Some(SignalNumber::SIGTSTP));
+        assert!(ld.take_output().ends_with(b"^C^\\^Z"));
+        // 没有 NOFLSH 时丢弃已有的输入
+        feed(&mut ld, b"\n");
+        assert_eq!(read_all(&mut ld), b"\n");
+
+        let mut ld = with_termios(|t| t.set_local_modes(t.local_modes() | LocalModes::NOFLSH));
+        feed(&mut ld, b"ab");
+        assert_eq!(ld.receive(0x03), Some(SignalNumber::SIGINT));
+        feed(&mut ld, b"\n");
+        assert_eq!(read_all(&mut ld), b"ab\n");
+
+        let mut ld = with_termios(|t| t.set_local_modes(t.local_modes() - LocalModes::ISIG));
+        feed(&mut ld, b"\x03\n");
+        assert_eq!(read_all(&mut ld), b"\x03\n");
+    }
+
+    #[test]
+    fn noncanonical_min_0_time_0() {
+        let mut ld = raw(0, 0);
+        let mut buf = [0u8; 8];
+        assert_eq!(ld.read(&mut buf, false), ReadStatus::Ready(0));
+        feed(&mut ld, b"ab");
+        assert_eq!(ld.read(&mut buf, false), ReadStatus::Ready(2));
+    }
+
+    #[test]
+    fn noncanonical_min_0_time_n() {
+        let mut ld = raw(0, 5);
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            ld.read(&mut buf, false),
+            ReadStatus::Block { timeout: Some(5) }
+        );
+        assert_eq!(ld.read(&mut buf, true), ReadStatus::Ready(0));
+        feed(&mut ld, b"a");
+        assert_eq!(ld.read(&mut buf, false), ReadStatus::Ready(1));
+    }
+
+    #[test]
+    fn noncanonical_min_n_time_0() {
+        let mut ld = raw(3, 0);
+        let mut buf = [0u8; 8];
+        feed(&mut ld, b"ab");
+        assert_eq!(
+            ld.read(&mut buf, false),
+            ReadStatus::Block { timeout: None }
+        );
+        // 缓冲区比 VMIN 小时，填满缓冲区即可返回
+        assert_eq!(ld.read(&mut buf[..2], false), ReadStatus::Ready(2));
+        feed(&mut ld, b"cde");
+        assert_eq!(ld.read(&mut buf, false), ReadStatus::Ready(3));
+        assert_eq!(&buf[..3], b"cde");
+    }
+
+    #[test]
+    fn noncanonical_min_n_time_n() {
+        let mut ld = raw(3, 2);
+        let mut buf = [0u8; 8];
+        // 收到第一个字节前没有超时
+        assert_eq!(
+            ld.read(&mut buf, false),
+            ReadStatus::Block { timeout: None }
+        );
+        feed(&mut ld, b"a");
+        assert_eq!(
+            ld.read(&mut buf, false),
+            ReadStatus::Block { timeout: Some(2) }
+        );
+        assert_eq!(ld.read(&mut buf, true), ReadStatus::Ready(1));
+        feed(&mut ld, b"bcd");
+        assert_eq!(ld.read(&mut buf, false), ReadStatus::Ready(3));
+    }
+
+    #[test]
+    fn switch_canonical_mode() {
+        let mut ld = LineDiscipline::default();
+        feed(&mut ld, b"abc");
+        assert_eq!(ld.available(), 0);
+        let canonical = *ld.termios();
+        let mut noncanonical = canonical;
+        noncanonical.cfmakeraw();
+        noncanonical.set_cc(ControlChar::VMIN, 1);
+        // 离开规范模式时，正在编辑的行立即可读
+        ld.set_termios(noncanonical);
+        assert_eq!(ld.available(), 3);
+        feed(&mut ld, b"d");
+        let mut buf = [0u8; 2];
+        assert_eq!(ld.read(&mut buf, false), ReadStatus::Ready(2));
+        // 进入规范模式时，剩余的输入被视为一行
+        ld.set_termios(canonical);
+        assert_eq!(read_all(&mut ld), b"cd");
+        feed(&mut ld, b"ef");
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            ld.read(&mut buf, false),
+            ReadStatus::Block { timeout: None }
+        );
+    }
+
+    fn open_pty() -> PtyPair {
+        let mut pty = PtyPair::new(3);
+        pty.set_locked(false);
+        pty.open_slave().unwrap();
+        pty
+    }
+
+    fn read_master(pty: &mut PtyPair) -> Vec<u8> {
+        let mut buf = [0u8; 64];
+        match pty.master_read(&mut buf) {
+            Ok(ReadStatus::Ready(n)) => buf[..n].to_vec(),
+            status => panic!("unexpected {:?}", status),
+        }
+    }
+
+    fn read_slave(pty: &mut PtyPair) -> Vec<u8> {
+        let mut buf = [0u8; 64];
+        match pty.slave_read(&mut buf, false) {
+            ReadStatus::Ready(n) => buf[..n].to_vec(),
+            status => panic!("unexpected {:?}", status),
+        }
+    }
+
+    #[test]
+    fn pty_slave_lock() {
+        let mut pty = PtyPair::new(3);
+        assert_eq!(pty.index(), 3);
+        assert!(pty.is_locked());
+        assert_eq!(pty.open_slave(), Err(LinuxErrno::EIO));
+        pty.set_locked(false);
+        assert_eq!(pty.open_slave(), Ok(()));
+        // 重新锁定只影响之后的打开
+        pty.set_locked(true);
+        assert_eq!(pty.open_slave(), Err(LinuxErrno::EIO));
+        assert_eq!(pty.slave_write(b"x"), Ok(1));
+        // 主设备关闭后不能再打开从设备
+        pty.set_locked(false);
+        pty.close(PtySide::Master);
+        assert_eq!(pty.open_slave(), Err(LinuxErrno::EIO));
+    }
+
+    #[test]
+    fn pty_round_trip() {
+        let mut pty = open_pty();
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            pty.master_read(&mut buf),
+            Ok(ReadStatus::Block { timeout: None })
+        );
+        assert_eq!(pty.master_write(b"hi"), []);
+        // 规范模式下行结束前从设备读不到数据，但回显已经可以被主设备读取
+        assert_eq!(pty.readable(PtySide::Slave), 0);
+        assert_eq!(pty.readable(PtySide::Master), 2);
+        assert_eq!(pty.master_write(b"\n"), []);
+        assert_eq!(pty.queued_output(PtySide::Master), 3);
+        assert_eq!(read_master(&mut pty), b"hi\r\n");
+        assert_eq!(read_slave(&mut pty), b"hi\n");
+        assert_eq!(pty.queued_output(PtySide::Master), 0);
+
+        assert_eq!(pty.slave_write(b"out\n"), Ok(4));
+        assert_eq!(pty.queued_output(PtySide::Slave), 5);
+        assert_eq!(read_master(&mut pty), b"out\r\n");
+    }
+
+    #[test]
+    fn pty_hangup() {
+        // 不是控制终端时关闭主设备不产生信号
+        let mut pty = open_pty();
+        assert_eq!(pty.close(PtySide::Master), None);
+
+        let mut pty = open_pty();
+        pty.set_controlling(1, 2, false).unwrap();
+        pty.master_write(b"partial");
+        assert_eq!(pty.close(PtySide::Master), Some(SignalNumber::SIGHUP));
+        assert_eq!(pty.slave_write(b"x"), Err(LinuxErrno::EIO));
+        // 从设备不再等待，读到文件结束
+        assert_eq!(read_slave(&mut pty), b"");
+
+        // 从设备关闭后，主设备读完剩余的数据再返回 EIO
+        let mut pty = open_pty();
+        pty.slave_write(b"bye").unwrap();
+        assert_eq!(pty.close(PtySide::Slave), None);
+        assert_eq!(read_master(&mut pty), b"bye");
+        let mut buf = [0u8; 8];
+        assert_eq!(pty.master_read(&mut buf), Err(LinuxErrno::EIO));
+    }
+
+    #[test]
+    fn pty_simulate_input() {
+        let mut pty = open_pty();
+        assert_eq!(pty.simulate_input(b'a'), None);
+        assert_eq!(pty.simulate_input(b'\n'), None);
+        assert_eq!(read_slave(&mut pty), b"a\n");
+        assert_eq!(pty.simulate_input(0x03), Some(SignalNumber::SIGINT));
+        assert_eq!(
+            pty.master_write(b"x\x1a\x1c"),
+            [SignalNumber::SIGTSTP, SignalNumber::SIGQUIT]
+        );
+    }
+
+    #[test]
+    fn pty_flush() {
+        let mut pty = open_pty();
+        let mut termios = *pty.termios();
+        termios.cfmakeraw();
+        pty.set_termios(termios);
+        pty.master_write(b"in");
+        pty.slave_write(b"out").unwrap();
+        // 对从设备而言，输入是主设备写入的数据
+        pty.flush(PtySide::Slave, FlushQueue::TCIFLUSH);
+        assert_eq!(pty.readable(PtySide::Slave), 0);
+        assert_eq!(pty.readable(PtySide::Master), 3);
+        pty.master_write(b"in");
+        // 对主设备而言，输入是从设备写入的数据
+        pty.flush(PtySide::Master, FlushQueue::TCIFLUSH);
+        assert_eq!(pty.readable(PtySide::Master), 0);
+        assert_eq!(pty.readable(PtySide::Slave), 2);
+        pty.slave_write(b"out").unwrap();
+        pty.flush(PtySide::Master, FlushQueue::TCIOFLUSH);
+        assert_eq!(pty.readable(PtySide::Master), 0);
+        assert_eq!(pty.readable(PtySide::Slave), 0);
+    }
+
+    #[test]
+    fn pty_flow_control() {
+        let mut pty = open_pty();
+        let mut buf = [0u8; 8];
+        pty.slave_write(b"a").unwrap();
+        pty.flow(FlowAction::TCOOFF);
+        assert_eq!(
+            pty.master_read(&mut buf),
+            Ok(ReadStatus::Block { timeout: None })
+        );
+        pty.flow(FlowAction::TCOON);
+        assert_eq!(read_master(&mut pty), b"a");
+        // IXON 时 VSTOP 与 VSTART 控制输出，且不作为输入
+        pty.master_write(b"\x13");
+        pty.slave_write(b"b").unwrap();
+        assert_eq!(
+            pty.master_read(&mut buf),
+            Ok(ReadStatus::Block { timeout: None })
+        );
+        pty.master_write(b"\x11");
+        assert_eq!(read_master(&mut pty), b"b");
+        assert_eq!(pty.readable(PtySide::Slave), 0);
+        // TCIOFF 与 TCION 向主设备发送 STOP 与 START 字符
+        pty.flow(FlowAction::TCIOFF);
+        pty.flow(FlowAction::TCION);
+        assert_eq!(read_master(&mut pty), b"\x13\x11");
+    }
+
+    #[test]
+    fn pty_controlling_terminal() {
+        let mut pty = open_pty();
+        assert_eq!(pty.session(), Err(LinuxErrno::ENOTTY));
+        assert_eq!(pty.foreground(1), Err(LinuxErrno::ENOTTY));
+        assert_eq!(pty.set_foreground(1, 10), Err(LinuxErrno::ENOTTY));
+
+        assert_eq!(pty.set_controlling(1, 10, false), Ok(()));
+        assert_eq!(pty.session(), Ok(1));
+        assert_eq!(pty.foreground(1), Ok(10));
+        assert_eq!(pty.foreground(2), Err(LinuxErrno::ENOTTY));
+        assert_eq!(pty.set_foreground(1, 11), Ok(()));
+        assert_eq!(pty.foreground(1), Ok(11));
+
+        // 其他会话只有在 steal 时才能夺取
+        assert_eq!(pty.set_controlling(2, 20, false), Err(LinuxErrno::EPERM));
+        assert_eq!(pty.set_controlling(2, 20, true), Ok(()));
+        assert_eq!(pty.session(), Ok(2));
+        assert_eq!(pty.foreground(2), Ok(20));
+
+        assert_eq!(pty.release_controlling(1, true), Err(LinuxErrno::ENOTTY));
+        assert_eq!(pty.release_controlling(2, false), Ok(None));
+        assert_eq!(pty.session(), Ok(2));
+        assert_eq!(pty.release_controlling(2, true), Ok(Some(20)));
+        assert_eq!(pty.session(), Err(LinuxErrno::ENOTTY));
+    }
+
+    #[test]
+    fn pty_winsize() {
+        let mut pty = open_pty();
+        assert_eq!(pty.winsize(), WinSize::new(24, 80));
+        // 没有前台进程组时不发送 SIGWINCH，但大小仍然更新
+        assert_eq!(pty.set_winsize(WinSize::new(30, 100)), None);
+        assert_eq!(pty.winsize(), WinSize::new(30, 100));
+        pty.set_controlling(1, 10, false).unwrap();
+        assert_eq!(pty.set_winsize(WinSize::new(30, 100)), None);
+        assert_eq!(
+            pty.set_winsize(WinSize::new(40, 120)),
+            Some(SignalNumber::SIGWINCH)
+        );
+        assert_eq!(pty.winsize(), WinSize::new(40, 120));
+        pty.release_controlling(1, true).unwrap();
+        assert_eq!(pty.set_winsize(WinSize::new(24, 80)), None);
+    }
+}